@@ -0,0 +1,157 @@
+use core::time::Duration;
+
+use crate::timer::Timer;
+
+/// How much randomness is added to the delays of a [`Backoff`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Jitter {
+    /// Always wait the exponential delay
+    #[default]
+    None,
+
+    /// Wait a random delay between zero and the exponential delay
+    Full,
+
+    /// Wait half of the exponential delay, plus a random delay up to the other half
+    Equal,
+}
+
+/// Exponential backoff policy, waiting on a pluggable [`Timer`].
+///
+/// The delay before the retry following attempt `n` is `base * factor^(n - 1)`, capped at `max_delay`,
+/// then randomized according to the [`Jitter`]. The randomness comes from a small generator that can be
+/// [seeded](Backoff::seed) for reproducible delays. Without the `std` feature, the default seed is fixed.
+///
+/// ```
+/// use core::time::Duration;
+/// use streamies::backoff::{Backoff, Jitter};
+///
+/// let timer = |_: Duration| core::future::ready(());
+/// let mut backoff = Backoff::new(timer)
+///     .base(Duration::from_millis(100))
+///     .max_delay(Duration::from_millis(300));
+///
+/// assert_eq!(backoff.delay(1), Duration::from_millis(100));
+/// assert_eq!(backoff.delay(2), Duration::from_millis(200));
+/// assert_eq!(backoff.delay(3), Duration::from_millis(300));
+///
+/// let mut backoff = backoff.jitter(Jitter::Full).seed(42);
+/// assert!(backoff.delay(2) <= Duration::from_millis(200));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Backoff<T> {
+    timer: T,
+    base: Duration,
+    factor: u32,
+    max_delay: Duration,
+    jitter: Jitter,
+    rng: SplitMix64,
+}
+
+impl<T: Timer> Backoff<T> {
+    /// Create a backoff starting at 100ms, doubling on each attempt up to 10s, without jitter
+    pub fn new(timer: T) -> Self {
+        Self {
+            timer,
+            base: Duration::from_millis(100),
+            factor: 2,
+            max_delay: Duration::from_secs(10),
+            jitter: Jitter::None,
+            rng: SplitMix64::new(default_seed()),
+        }
+    }
+
+    /// Set the delay after the first attempt
+    pub fn base(mut self, base: Duration) -> Self {
+        self.base = base;
+        self
+    }
+
+    /// Set the multiplier applied to the delay on each attempt
+    pub fn factor(mut self, factor: u32) -> Self {
+        self.factor = factor;
+        self
+    }
+
+    /// Set the maximum delay, before jitter
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Set the [`Jitter`] of the delays
+    pub fn jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Seed the random generator used for the jitter
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.rng = SplitMix64::new(seed);
+        self
+    }
+
+    /// Compute the delay to wait after the `attempt`th attempt failed. Attempts start at 1
+    pub fn delay(&mut self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1);
+        let delay = self
+            .base
+            .saturating_mul(self.factor.saturating_pow(exponent))
+            .min(self.max_delay);
+
+        match self.jitter {
+            Jitter::None => delay,
+            Jitter::Full => self.rng.below(delay),
+            Jitter::Equal => delay / 2 + self.rng.below(delay - delay / 2),
+        }
+    }
+
+    /// Sleep for the delay following the `attempt`th attempt
+    pub fn sleep(&mut self, attempt: u32) -> T::Sleep {
+        let delay = self.delay(attempt);
+        self.timer.sleep(delay)
+    }
+}
+
+#[cfg(feature = "std")]
+fn default_seed() -> u64 {
+    use core::hash::BuildHasher as _;
+
+    // The hasher keys are randomly seeded by the OS
+    std::collections::hash_map::RandomState::new().hash_one(0u8)
+}
+
+#[cfg(not(feature = "std"))]
+fn default_seed() -> u64 {
+    0x853c_49e6_748f_ea9b
+}
+
+/// The SplitMix64 generator. Fast, small, and good enough for jitter
+#[derive(Debug, Clone)]
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Return a random duration between zero and `max`, both included
+    fn below(&mut self, max: Duration) -> Duration {
+        let max = u64::try_from(max.as_nanos()).unwrap_or(u64::MAX);
+        let nanos = match max.checked_add(1) {
+            Some(bound) => self.next_u64() % bound,
+            None => self.next_u64(),
+        };
+        Duration::from_nanos(nanos)
+    }
+}
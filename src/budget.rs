@@ -0,0 +1,84 @@
+use core::num::NonZeroUsize;
+
+/// The number of inner polls an adaptor is allowed to do by default, before yielding back to the executor.
+pub const DEFAULT_BUDGET: NonZeroUsize = match NonZeroUsize::new(32) {
+    Some(budget) => budget,
+    None => unreachable!(),
+};
+
+/// Cooperative budget of the adaptors looping on their inner stream.
+///
+/// Adaptors like [`unique_by`](crate::Streamies::unique_by) or [`chunks_ok`](crate::TryStreamies::chunks_ok)
+/// keep polling their inner stream until they find something to return. Against an always ready
+/// stream, this can monopolize the executor's thread for a long time.
+///
+/// Once the budget is spent, the adaptor wakes its own task and returns `Poll::Pending`, giving the
+/// executor a chance to run other tasks. The budget is reset on each call to `poll_next`.
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use core::num::NonZeroUsize;
+/// use core::task::Poll;
+/// use futures::stream::{self, StreamExt};
+/// use streamies::Budget;
+/// use streamies::TryStreamies as _;
+///
+/// let budget = Budget::new(NonZeroUsize::new(8).unwrap());
+/// let mut stream = stream::iter(vec![Ok::<i32, ()>(1); 100]).chunks_ok(50).with_budget(budget);
+///
+/// assert_eq!(futures::poll!(stream.next()), Poll::Pending); // Only 8 items got buffered
+/// assert_eq!(stream.try_collect_vec().await, Ok(vec![vec![1; 50], vec![1; 50]]));
+///
+/// let mut stream = stream::iter(vec![Ok::<i32, ()>(1); 100]).chunks_ok(50).with_budget(Budget::unlimited());
+/// assert_eq!(futures::poll!(stream.next()), Poll::Ready(Some(Ok(vec![1; 50]))));
+/// # });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Budget {
+    limit: Option<NonZeroUsize>,
+}
+
+impl Budget {
+    /// Create a budget allowing `limit` inner polls per call to `poll_next`
+    pub const fn new(limit: NonZeroUsize) -> Self {
+        Self { limit: Some(limit) }
+    }
+
+    /// Create a budget that never yields back to the executor
+    pub const fn unlimited() -> Self {
+        Self { limit: None }
+    }
+
+    /// Return the number of inner polls allowed, or `None` if unlimited
+    pub const fn limit(&self) -> Option<NonZeroUsize> {
+        self.limit
+    }
+
+    /// Start spending the budget for the current poll
+    pub(crate) fn start(&self) -> Remaining {
+        Remaining(self.limit.map(NonZeroUsize::get))
+    }
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Self::new(DEFAULT_BUDGET)
+    }
+}
+
+/// The budget left for the current poll
+#[derive(Debug)]
+pub(crate) struct Remaining(Option<usize>);
+
+impl Remaining {
+    /// Spend one unit of budget. Returns `true` if the budget is exhausted
+    pub(crate) fn tick(&mut self) -> bool {
+        match &mut self.0 {
+            Some(remaining) => {
+                *remaining = remaining.saturating_sub(1);
+                *remaining == 0
+            }
+            None => false,
+        }
+    }
+}
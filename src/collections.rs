@@ -0,0 +1,16 @@
+//! Collections that depend on the `std` feature.
+//!
+//! Without `std`, the hash based collections are provided by [`hashbrown`](https://docs.rs/hashbrown).
+
+#[cfg(not(feature = "std"))]
+pub(crate) use hashbrown::hash_map::Entry;
+#[cfg(not(feature = "std"))]
+pub(crate) use hashbrown::HashMap;
+#[cfg(not(feature = "std"))]
+pub(crate) use hashbrown::HashSet;
+#[cfg(feature = "std")]
+pub(crate) use std::collections::hash_map::Entry;
+#[cfg(feature = "std")]
+pub(crate) use std::collections::HashMap;
+#[cfg(feature = "std")]
+pub(crate) use std::collections::HashSet;
@@ -0,0 +1,92 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::future::FusedFuture;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    #[project = StateProj]
+    #[project_replace = StateProjReplace]
+    #[derive(Debug)]
+    enum State<Fut> {
+        Some { #[pin] fut: Fut },
+        None,
+        Done,
+    }
+}
+
+pin_project! {
+    /// Future resolving an optional future, into an option of its output.
+    ///
+    /// It is returned by [`OptionExt`](crate::futuries::OptionExt) and allocates nothing. Like
+    /// [`FutureResult`](crate::futuries::future_result::FutureResult), polling it again after it completed returns `Poll::Pending`.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use core::future::ready;
+    /// use futures::future::FusedFuture;
+    /// use streamies::futuries::future_option::FutureOption;
+    ///
+    /// let mut fut = FutureOption::<core::future::Ready<i32>>::none();
+    /// assert_eq!((&mut fut).await, None);
+    /// assert!(fut.is_terminated());
+    ///
+    /// assert_eq!(FutureOption::some(ready(5)).await, Some(5));
+    /// # });
+    /// ```
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct FutureOption<Fut> {
+        #[pin]
+        state: State<Fut>,
+    }
+}
+
+impl<Fut> FutureOption<Fut> {
+    /// Create a future resolving `fut` into a `Some` value
+    pub fn some(fut: Fut) -> Self {
+        Self {
+            state: State::Some { fut },
+        }
+    }
+
+    /// Create a future resolving to `None`
+    pub fn none() -> Self {
+        Self { state: State::None }
+    }
+}
+
+impl<Fut> Future for FutureOption<Fut>
+where
+    Fut: Future,
+{
+    type Output = Option<Fut::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.project().state;
+
+        let output = match state.as_mut().project() {
+            StateProj::Some { fut } => match fut.poll(cx) {
+                Poll::Ready(output) => Some(output),
+                Poll::Pending => return Poll::Pending,
+            },
+            StateProj::None => None,
+            StateProj::Done => return Poll::Pending,
+        };
+
+        // Drop the completed future, so it never gets polled again
+        state.project_replace(State::Done);
+        Poll::Ready(output)
+    }
+}
+
+impl<Fut> FusedFuture for FutureOption<Fut>
+where
+    Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        matches!(self.state, State::Done)
+    }
+}
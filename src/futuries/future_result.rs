@@ -1,41 +1,62 @@
 use core::future::Future;
 use core::pin::Pin;
-use core::task::ready;
 use core::task::Context;
 use core::task::Poll;
 
-use futures::stream::FuturesUnordered;
-use futures::StreamExt as _;
+use futures_core::future::FusedFuture;
 use pin_project_lite::pin_project;
 
-// It would be better to use a enum, but that would require manual pin implementation
 pin_project! {
+    #[project = StateProj]
+    #[project_replace = StateProjReplace]
+    #[derive(Debug)]
+    enum State<FutOk, FutErr> {
+        Ok { #[pin] fut: FutOk },
+        Err { #[pin] fut: FutErr },
+        Done,
+    }
+}
+
+pin_project! {
+    /// Future resolving either the `Ok` or the `Err` future of a result, into a result of their outputs.
+    ///
+    /// It is returned by [`ResultExt`](crate::futuries::ResultExt) and allocates nothing. Once completed, polling it again
+    /// returns `Poll::Pending`, and [`is_terminated`](FusedFuture::is_terminated) returns `true`.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use core::future::{Ready, ready};
+    /// use futures::future::{FusedFuture, FutureExt as _};
+    /// use streamies::futuries::future_result::FutureResult;
+    ///
+    /// let mut fut = FutureResult::<_, Ready<()>>::ok(ready(5));
+    /// assert!(!fut.is_terminated());
+    ///
+    /// assert_eq!((&mut fut).await, Ok(5));
+    /// assert!(fut.is_terminated());
+    /// assert!(fut.now_or_never().is_none()); // Polling again is safe
+    /// # });
+    /// ```
     #[derive(Debug)]
     #[must_use = "futures do nothing unless you `.await` or poll them"]
-    pub struct FutureResult<FutOk, FutErr>{
-        #[pin]
-        ok: FuturesUnordered<FutOk>,
+    pub struct FutureResult<FutOk, FutErr> {
         #[pin]
-        err: FuturesUnordered<FutErr>,
+        state: State<FutOk, FutErr>,
     }
 }
 
 impl<FutOk, FutErr> FutureResult<FutOk, FutErr> {
+    /// Create a future resolving `ok` into an `Ok` value
     pub fn ok(ok: FutOk) -> Self {
-        let value = FuturesUnordered::new();
-        value.push(ok);
         Self {
-            ok: value,
-            err: FuturesUnordered::new(),
+            state: State::Ok { fut: ok },
         }
     }
 
+    /// Create a future resolving `err` into an `Err` value
     pub fn err(err: FutErr) -> Self {
-        let value = FuturesUnordered::new();
-        value.push(err);
         Self {
-            ok: FuturesUnordered::new(),
-            err: value,
+            state: State::Err { fut: err },
         }
     }
 }
@@ -48,18 +69,32 @@ where
     type Output = Result<FutOk::Output, FutErr::Output>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let mut this = self.project();
+        let mut state = self.project().state;
 
-        match ready!(this.ok.poll_next_unpin(cx)) {
-            None => {}
-            Some(val) => return Poll::Ready(Ok(val)),
-        }
+        let output = match state.as_mut().project() {
+            StateProj::Ok { fut } => match fut.poll(cx) {
+                Poll::Ready(output) => Ok(output),
+                Poll::Pending => return Poll::Pending,
+            },
+            StateProj::Err { fut } => match fut.poll(cx) {
+                Poll::Ready(output) => Err(output),
+                Poll::Pending => return Poll::Pending,
+            },
+            StateProj::Done => return Poll::Pending,
+        };
 
-        match ready!(this.err.poll_next_unpin(cx)) {
-            None => {}
-            Some(val) => return Poll::Ready(Err(val)),
-        }
+        // Drop the completed future, so it never gets polled again
+        state.project_replace(State::Done);
+        Poll::Ready(output)
+    }
+}
 
-        panic!("FutureResult has no future saved.")
+impl<FutOk, FutErr> FusedFuture for FutureResult<FutOk, FutErr>
+where
+    FutOk: Future,
+    FutErr: Future,
+{
+    fn is_terminated(&self) -> bool {
+        matches!(self.state, State::Done)
     }
 }
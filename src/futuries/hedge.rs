@@ -0,0 +1,89 @@
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+
+use pin_project_lite::pin_project;
+
+use crate::timer::Timer;
+
+pin_project! {
+    /// Future for the [`hedge`](crate::Futuries::hedge) method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Hedge<Fut, F, T>
+    where
+        T: Timer,
+    {
+        #[pin]
+        first: Fut,
+        #[pin]
+        second: Option<Fut>,
+        #[pin]
+        sleep: Option<T::Sleep>,
+        factory: Option<F>,
+    }
+}
+
+impl<Fut, F, T> Hedge<Fut, F, T>
+where
+    Fut: Future,
+    F: FnOnce() -> Fut,
+    T: Timer,
+{
+    pub(crate) fn new(first: Fut, sleep: T::Sleep, factory: F) -> Self {
+        Self {
+            first,
+            second: None,
+            sleep: Some(sleep),
+            factory: Some(factory),
+        }
+    }
+}
+
+impl<Fut, F, T> Future for Hedge<Fut, F, T>
+where
+    Fut: Future,
+    F: FnOnce() -> Fut,
+    T: Timer,
+{
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        if let Poll::Ready(output) = this.first.poll(cx) {
+            return Poll::Ready(output);
+        }
+
+        // Launch the second attempt once the first one took too long
+        if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+            if sleep.poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+
+            this.sleep.set(None);
+            if let Some(factory) = this.factory.take() {
+                this.second.set(Some(factory()));
+            }
+        }
+
+        match this.second.as_pin_mut() {
+            Some(second) => second.poll(cx),
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl<Fut, F, T> fmt::Debug for Hedge<Fut, F, T>
+where
+    Fut: fmt::Debug,
+    T: Timer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Hedge")
+            .field("first", &self.first)
+            .field("second", &self.second)
+            .finish_non_exhaustive()
+    }
+}
@@ -2,9 +2,143 @@ use core::future::ready;
 use core::future::Future;
 use core::future::Ready;
 
+use core::time::Duration;
+
+use crate::backoff::Backoff;
+use crate::futuries::future_option::FutureOption;
 use crate::futuries::future_result::FutureResult;
+use crate::timer::Timer;
+
+pub use crate::futuries::hedge::Hedge;
+pub use crate::futuries::retry::Retry;
+pub use crate::futuries::timeout::Elapsed;
+pub use crate::futuries::timeout::Timeout;
 
+pub mod future_option;
 pub mod future_result;
+pub mod hedge;
+pub mod retry;
+pub mod timeout;
+
+/// Extension trait for [`Future`]s.
+///
+/// The time based adaptors wait on a pluggable [`Timer`], so they work with any runtime. Any
+/// `Fn(Duration) -> impl Future<Output = ()>` is a timer, so with smol, a closure around
+/// [`async_io::Timer::after`](https://docs.rs/async-io/latest/async_io/struct.Timer.html#method.after) can be used.
+pub trait Futuries: Future {
+    /// Fail with [`Elapsed`] if the future doesn't complete within `duration`, measured by `timer`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use core::task::Poll;
+    /// use core::time::Duration;
+    /// use futures::future;
+    /// use streamies::timer::ManualClock;
+    /// use streamies::Futuries as _;
+    ///
+    /// let clock = ManualClock::new();
+    ///
+    /// // The future completes before the deadline
+    /// assert_eq!(future::ready(1).timeout(clock.clone(), Duration::from_secs(5)).await, Ok(1));
+    ///
+    /// let mut stuck = future::pending::<()>().timeout(clock.clone(), Duration::from_secs(5));
+    /// assert_eq!(futures::poll!(&mut stuck), Poll::Pending);
+    ///
+    /// clock.advance(Duration::from_secs(5));
+    /// assert!(stuck.await.is_err());
+    /// # })
+    /// ```
+    fn timeout<T>(self, timer: T, duration: Duration) -> Timeout<Self, T>
+    where
+        Self: Sized,
+        T: Timer,
+    {
+        Timeout::new(self, timer.sleep(duration))
+    }
+
+    /// Retry a fallible future, creating the next attempts with `factory`, and waiting between them according to `backoff`.
+    ///
+    /// The future itself is the first attempt. By default, it is tried up to 3 times. Use [`Retry::max_attempts`] to
+    /// change it. The error of the last attempt is returned if they all fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use core::pin::pin;
+    /// use core::task::Poll;
+    /// use core::time::Duration;
+    /// use std::cell::Cell;
+    /// use streamies::timer::ManualClock;
+    /// use streamies::{Backoff, Futuries as _};
+    ///
+    /// let clock = ManualClock::new();
+    /// let calls = Cell::new(0);
+    /// let call = || async {
+    ///     calls.set(calls.get() + 1);
+    ///     if calls.get() < 3 { Err("busy") } else { Ok(calls.get()) }
+    /// };
+    ///
+    /// let backoff = Backoff::new(clock.clone()).base(Duration::from_secs(1));
+    /// let mut retry = pin!(call().retry(call, backoff).max_attempts(5));
+    ///
+    /// assert_eq!(futures::poll!(&mut retry), Poll::Pending); // Waiting 1s after the first attempt
+    /// clock.advance(Duration::from_secs(1));
+    /// assert_eq!(futures::poll!(&mut retry), Poll::Pending); // Then 2s after the second one
+    /// clock.advance(Duration::from_secs(2));
+    /// assert_eq!(retry.await, Ok(3));
+    /// # })
+    /// ```
+    fn retry<F, T, O, E>(self, factory: F, backoff: Backoff<T>) -> Retry<Self, F, T>
+    where
+        Self: Sized + Future<Output = Result<O, E>>,
+        F: FnMut() -> Self,
+        T: Timer,
+    {
+        Retry::new(self, factory, backoff)
+    }
+
+    /// Launch a second attempt with `factory` if the future hasn't completed after `delay`, and return the output of
+    /// whichever completes first. The other attempt is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use core::task::Poll;
+    /// use core::time::Duration;
+    /// use futures::future::{self, FutureExt as _};
+    /// use streamies::timer::ManualClock;
+    /// use streamies::Futuries as _;
+    ///
+    /// let clock = ManualClock::new();
+    /// let delay = Duration::from_millis(50);
+    ///
+    /// // The first attempt completes in time, so no second one is launched
+    /// let fast = future::ready(1).boxed().hedge(clock.clone(), delay, || unreachable!());
+    /// assert_eq!(fast.await, 1);
+    ///
+    /// // The first attempt is stuck
+    /// let mut stuck = future::pending().boxed().hedge(clock.clone(), delay, || future::ready(2).boxed());
+    /// assert_eq!(futures::poll!(&mut stuck), Poll::Pending);
+    ///
+    /// clock.advance(delay);
+    /// assert_eq!(stuck.await, 2);
+    /// # })
+    /// ```
+    fn hedge<F, T>(self, timer: T, delay: Duration, factory: F) -> Hedge<Self, F, T>
+    where
+        Self: Sized,
+        F: FnOnce() -> Self,
+        T: Timer,
+    {
+        Hedge::new(self, timer.sleep(delay), factory)
+    }
+}
+
+impl<Fut: Future + ?Sized> Futuries for Fut {}
 
 pub trait ResultExt<T, E>: Sized {
     /// Extract the contained futures into a future that return a result.
@@ -98,3 +232,105 @@ impl<T, E> ResultExt<T, E> for Result<T, E> {
         }
     }
 }
+
+pub trait OptionExt<T>: Sized {
+    /// Extract the contained future into a future that return an option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use core::future::{Ready, ready};
+    /// use streamies::futuries::OptionExt as _;
+    ///
+    /// let foo = Some(ready(5)).extract_future();
+    /// assert_eq!(foo.await, Some(5));
+    ///
+    /// let bar = None::<Ready<i64>>.extract_future();
+    /// assert_eq!(bar.await, None);
+    /// # })
+    /// ```
+    fn extract_future(self) -> FutureOption<T>
+    where
+        T: Future;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    fn extract_future(self) -> FutureOption<T>
+    where
+        T: Future,
+    {
+        match self {
+            Some(v) => FutureOption::some(v),
+            None => FutureOption::none(),
+        }
+    }
+}
+
+pub trait ResultOptionExt<Fut, E>: Sized {
+    /// Extract the future nested in the `Ok` value, into a future that return a `Result<Option<T>, E>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use core::future::{Ready, ready};
+    /// use streamies::futuries::ResultOptionExt as _;
+    ///
+    /// let foo = Ok::<_, String>(Some(ready(5))).extract_nested_future();
+    /// assert_eq!(foo.await, Ok(Some(5)));
+    ///
+    /// let bar = Ok::<Option<Ready<i64>>, String>(None).extract_nested_future();
+    /// assert_eq!(bar.await, Ok(None));
+    ///
+    /// let baz = Err::<Option<Ready<i64>>, _>("Hello there!".to_string()).extract_nested_future();
+    /// assert_eq!(baz.await, Err("Hello there!".to_string()));
+    /// # })
+    /// ```
+    fn extract_nested_future(self) -> FutureResult<FutureOption<Fut>, Ready<E>>
+    where
+        Fut: Future;
+}
+
+impl<Fut, E> ResultOptionExt<Fut, E> for Result<Option<Fut>, E> {
+    fn extract_nested_future(self) -> FutureResult<FutureOption<Fut>, Ready<E>>
+    where
+        Fut: Future,
+    {
+        self.map(OptionExt::extract_future).extract_future_ok()
+    }
+}
+
+pub trait OptionResultExt<Fut, E>: Sized {
+    /// Extract the future nested in the `Ok` value, into a future that return an `Option<Result<T, E>>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use core::future::{Ready, ready};
+    /// use streamies::futuries::OptionResultExt as _;
+    ///
+    /// let foo = Some(Ok::<_, String>(ready(5))).extract_nested_future();
+    /// assert_eq!(foo.await, Some(Ok(5)));
+    ///
+    /// let bar = Some(Err::<Ready<i64>, _>("Hello there!".to_string())).extract_nested_future();
+    /// assert_eq!(bar.await, Some(Err("Hello there!".to_string())));
+    ///
+    /// let baz = None::<Result<Ready<i64>, String>>.extract_nested_future();
+    /// assert_eq!(baz.await, None);
+    /// # })
+    /// ```
+    fn extract_nested_future(self) -> FutureOption<FutureResult<Fut, Ready<E>>>
+    where
+        Fut: Future;
+}
+
+impl<Fut, E> OptionResultExt<Fut, E> for Option<Result<Fut, E>> {
+    fn extract_nested_future(self) -> FutureOption<FutureResult<Fut, Ready<E>>>
+    where
+        Fut: Future,
+    {
+        self.map(ResultExt::extract_future_ok).extract_future()
+    }
+}
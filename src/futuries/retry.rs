@@ -0,0 +1,103 @@
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+
+use pin_project_lite::pin_project;
+
+use crate::backoff::Backoff;
+use crate::timer::Timer;
+
+pin_project! {
+    /// Future for the [`retry`](crate::Futuries::retry) method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Retry<Fut, F, T>
+    where
+        T: Timer,
+    {
+        #[pin]
+        fut: Fut,
+        #[pin]
+        sleep: Option<T::Sleep>,
+        factory: F,
+        attempt: u32,
+        max_attempts: u32,
+        backoff: Backoff<T>,
+    }
+}
+
+impl<Fut, F, T, O, E> Retry<Fut, F, T>
+where
+    Fut: Future<Output = Result<O, E>>,
+    F: FnMut() -> Fut,
+    T: Timer,
+{
+    pub(crate) fn new(fut: Fut, factory: F, backoff: Backoff<T>) -> Self {
+        Self {
+            fut,
+            sleep: None,
+            factory,
+            attempt: 1,
+            max_attempts: 3,
+            backoff,
+        }
+    }
+
+    /// Set the maximum number of attempts, the first one included. Defaults to 3
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `max_attempts` is zero.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        assert!(max_attempts > 0, "at least one attempt must be allowed");
+        self.max_attempts = max_attempts;
+        self
+    }
+}
+
+impl<Fut, F, T, O, E> Future for Retry<Fut, F, T>
+where
+    Fut: Future<Output = Result<O, E>>,
+    F: FnMut() -> Fut,
+    T: Timer,
+{
+    type Output = Result<O, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            // Waiting before the next attempt
+            if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+                ready!(sleep.poll(cx));
+                this.sleep.set(None);
+                this.fut.set((this.factory)());
+                *this.attempt += 1;
+            }
+
+            match ready!(this.fut.as_mut().poll(cx)) {
+                Err(_) if this.attempt < this.max_attempts => {
+                    this.sleep.set(Some(this.backoff.sleep(*this.attempt)));
+                }
+                output => return Poll::Ready(output),
+            }
+        }
+    }
+}
+
+impl<Fut, F, T> fmt::Debug for Retry<Fut, F, T>
+where
+    Fut: fmt::Debug,
+    T: Timer + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Retry")
+            .field("fut", &self.fut)
+            .field("attempt", &self.attempt)
+            .field("max_attempts", &self.max_attempts)
+            .field("backoff", &self.backoff)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,68 @@
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+
+use pin_project_lite::pin_project;
+
+pub use crate::timer::Elapsed;
+use crate::timer::Timer;
+
+pin_project! {
+    /// Future for the [`timeout`](crate::Futuries::timeout) method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Timeout<Fut, T>
+    where
+        T: Timer,
+    {
+        #[pin]
+        fut: Fut,
+        #[pin]
+        sleep: T::Sleep,
+    }
+}
+
+impl<Fut, T> Timeout<Fut, T>
+where
+    Fut: Future,
+    T: Timer,
+{
+    pub(crate) fn new(fut: Fut, sleep: T::Sleep) -> Self {
+        Self { fut, sleep }
+    }
+}
+
+impl<Fut, T> Future for Timeout<Fut, T>
+where
+    Fut: Future,
+    T: Timer,
+{
+    type Output = Result<Fut::Output, Elapsed>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        // The future gets a last chance if it completes along the deadline
+        if let Poll::Ready(output) = this.fut.poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+
+        match this.sleep.poll(cx) {
+            Poll::Ready(()) => Poll::Ready(Err(Elapsed(()))),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<Fut, T> fmt::Debug for Timeout<Fut, T>
+where
+    Fut: fmt::Debug,
+    T: Timer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Timeout")
+            .field("fut", &self.fut)
+            .finish_non_exhaustive()
+    }
+}
@@ -1,18 +1,47 @@
-#[cfg(feature = "futures")]
+#![no_std]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod backoff;
+pub mod budget;
+pub(crate) mod collections;
 pub mod futuries;
+pub(crate) mod lock;
 pub mod macros;
 #[cfg(feature = "smol")]
 pub mod smol_streamies;
-#[cfg(feature = "futures")]
+#[cfg(feature = "std")]
+pub mod spawner;
 pub mod streamies;
-#[cfg(feature = "futures")]
+pub mod timer;
+#[cfg(feature = "tokio")]
+pub mod tokio_streamies;
 pub mod try_streamies;
 
-#[cfg(feature = "futures")]
+pub use crate::backoff::Backoff;
+pub use crate::budget::Budget;
 pub use crate::futuries::*;
-#[cfg(feature = "futures")]
+#[cfg(feature = "smol")]
+pub use crate::smol_streamies::*;
+#[cfg(feature = "std")]
+pub use crate::spawner::SpawnError;
+#[cfg(feature = "std")]
+pub use crate::spawner::Spawner;
+#[cfg(feature = "tokio")]
+pub use crate::spawner::TokioSpawner;
 pub use crate::streamies::*;
-#[cfg(feature = "futures")]
+pub use crate::timer::Clock;
+pub use crate::timer::Timer;
+#[cfg(feature = "tokio")]
+pub use crate::timer::TokioTimer;
+#[cfg(feature = "tokio")]
+pub use crate::tokio_streamies::*;
 pub use crate::try_streamies::*;
 #[cfg(feature = "futures")]
 pub use futures::{Stream, StreamExt, TryStream, TryStreamExt};
+// futures-lite's `StreamExt` differs from the futures one, and has no `TryStreamExt` counterpart,
+// so only the stream traits themselves are re-exported without the futures feature
+#[cfg(all(feature = "smol", not(feature = "futures")))]
+pub use futures_core::{Stream, TryStream};
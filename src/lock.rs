@@ -0,0 +1,124 @@
+//! A minimal mutex, backed by `std`'s one when available, and by a spin lock otherwise.
+//!
+//! It is only used for short critical sections, that never poll or wake anything while locked.
+
+use core::ops::Deref;
+use core::ops::DerefMut;
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub(crate) struct Lock<T> {
+    mutex: std::sync::Mutex<T>,
+}
+
+#[cfg(feature = "std")]
+impl<T> Lock<T> {
+    pub(crate) fn new(value: T) -> Self {
+        Self {
+            mutex: std::sync::Mutex::new(value),
+        }
+    }
+
+    /// Lock the mutex, ignoring the poisoning. The state is always left consistent between two awaits
+    pub(crate) fn lock(&self) -> LockGuard<'_, T> {
+        LockGuard {
+            guard: self
+                .mutex
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub(crate) struct LockGuard<'a, T> {
+    guard: std::sync::MutexGuard<'a, T>,
+}
+
+#[cfg(feature = "std")]
+impl<T> Deref for LockGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> DerefMut for LockGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+#[cfg(not(feature = "std"))]
+#[derive(Debug, Default)]
+pub(crate) struct Lock<T> {
+    locked: core::sync::atomic::AtomicBool,
+    value: core::cell::UnsafeCell<T>,
+}
+
+// SAFETY: The value is only accessed through the guard, and there's at most one guard at a time
+#[cfg(not(feature = "std"))]
+unsafe impl<T: Send> Send for Lock<T> {}
+#[cfg(not(feature = "std"))]
+unsafe impl<T: Send> Sync for Lock<T> {}
+
+#[cfg(not(feature = "std"))]
+impl<T> Lock<T> {
+    pub(crate) fn new(value: T) -> Self {
+        Self {
+            locked: core::sync::atomic::AtomicBool::new(false),
+            value: core::cell::UnsafeCell::new(value),
+        }
+    }
+
+    /// Spin until the lock is acquired
+    pub(crate) fn lock(&self) -> LockGuard<'_, T> {
+        use core::sync::atomic::Ordering;
+
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            while self.locked.load(Ordering::Relaxed) {
+                core::hint::spin_loop();
+            }
+        }
+
+        LockGuard { lock: self }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) struct LockGuard<'a, T> {
+    lock: &'a Lock<T>,
+}
+
+#[cfg(not(feature = "std"))]
+impl<T> Deref for LockGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: The guard holds the lock
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl<T> DerefMut for LockGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: The guard holds the lock, and is borrowed mutably
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl<T> Drop for LockGuard<'_, T> {
+    fn drop(&mut self) {
+        self.lock
+            .locked
+            .store(false, core::sync::atomic::Ordering::Release);
+    }
+}
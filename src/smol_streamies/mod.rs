@@ -4,9 +4,26 @@ use crate::smol_streamies::ready_chunks::ReadyChunks;
 
 pub mod ready_chunks;
 
+/// [`TryStreamies`](crate::TryStreamies) under its smol name.
+///
+/// The error-passing adaptors are implemented on top of [`futures_core`]'s `TryStream`,
+/// so they already work on [futures_lite] streams of `Result<T, E>`.
+///
+/// ```
+/// # futures_lite::future::block_on(async {
+/// use futures_lite::stream;
+/// use streamies::SmolTryStreamies as _;
+///
+/// let stream = stream::iter(vec![Ok::<i32, String>(1), Ok(2), Ok(3)]);
+/// assert_eq!(stream.try_collect_vec().await, Ok(vec![1, 2, 3]));
+/// # });
+/// ```
+pub use crate::try_streamies::TryStreamies as SmolTryStreamies;
+
 /// Streamies trait using smol's crate ecosystem.
 ///
-/// This trait also port some of futures's methods that are lacking from [futures_lite],
+/// The crate's adaptors are all available through [`Streamies`](crate::Streamies) and [`TryStreamies`](crate::TryStreamies),
+/// whichever backend is used. This trait only port some of futures's methods that are lacking from [futures_lite],
 /// but still following the crate's phylosophy
 pub trait SmolStreamies: Stream {
     /// An adaptor for chunking up ready items of the stream inside a vector.
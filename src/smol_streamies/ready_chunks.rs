@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use core::pin::Pin;
 use core::task::Context;
 use core::task::Poll;
@@ -0,0 +1,270 @@
+use alloc::string::String;
+use alloc::string::ToString as _;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+use std::boxed::Box;
+use std::panic::AssertUnwindSafe;
+
+use pin_project_lite::pin_project;
+
+/// Error of a task run by a [`Spawner`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SpawnError {
+    /// The task panicked
+    Panicked {
+        /// The panic message, if it was a string
+        message: Option<String>,
+    },
+
+    /// The task got cancelled before completing, for example by the shutdown of the executor
+    Cancelled,
+}
+
+impl SpawnError {
+    fn panicked(payload: Box<dyn core::any::Any + Send>) -> Self {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => Some(*message),
+            Err(payload) => payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string()),
+        };
+
+        Self::Panicked { message }
+    }
+}
+
+impl fmt::Display for SpawnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Panicked {
+                message: Some(message),
+            } => write!(f, "the spawned task panicked: {message}"),
+            Self::Panicked { message: None } => write!(f, "the spawned task panicked"),
+            Self::Cancelled => write!(f, "the spawned task got cancelled"),
+        }
+    }
+}
+
+impl core::error::Error for SpawnError {}
+
+/// An executor able to run futures in the background, used by the adaptors that offload their work to other tasks.
+///
+/// Panics of the spawned futures are caught, and returned as [`SpawnError::Panicked`] by their handle.
+/// Implementations are provided for:
+/// - [`futures::executor::ThreadPool`](https://docs.rs/futures/latest/futures/executor/struct.ThreadPool.html), with the `thread-pool` feature
+/// - [`async_executor::Executor`](https://docs.rs/async-executor/latest/async_executor/struct.Executor.html), used by smol, with the `smol-executor` feature
+/// - [`TokioSpawner`](https://docs.rs/streamies/latest/streamies/spawner/struct.TokioSpawner.html) and
+///   [`tokio::runtime::Handle`](https://docs.rs/tokio/latest/tokio/runtime/struct.Handle.html), with the `tokio` feature
+///
+/// It is also implemented for references and [`Arc`](std::sync::Arc)s of spawners.
+///
+/// ```
+/// # #[cfg(feature = "thread-pool")]
+/// # futures::executor::block_on(async {
+/// use futures::executor::ThreadPool;
+/// use futures::stream;
+/// use streamies::Streamies as _;
+///
+/// let pool = ThreadPool::new().unwrap();
+/// let sums = stream::iter(vec![10u64, 100, 1000])
+///     .par_map_spawned(3, &pool, |n| async move { (1..=n).sum::<u64>() })
+///     .collect_vec()
+///     .await;
+///
+/// assert_eq!(sums, vec![Ok(55), Ok(5050), Ok(500500)]);
+/// # });
+/// ```
+pub trait Spawner {
+    /// The handle of a spawned task, resolving to its output
+    type Handle<T: Send + 'static>: Future<Output = Result<T, SpawnError>> + Unpin;
+
+    /// Run `fut` in the background, returning a handle on its output
+    fn spawn<Fut>(&self, fut: Fut) -> Self::Handle<Fut::Output>
+    where
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static;
+}
+
+impl<S: Spawner + ?Sized> Spawner for &S {
+    type Handle<T: Send + 'static> = S::Handle<T>;
+
+    fn spawn<Fut>(&self, fut: Fut) -> Self::Handle<Fut::Output>
+    where
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static,
+    {
+        (**self).spawn(fut)
+    }
+}
+
+impl<S: Spawner + ?Sized> Spawner for std::sync::Arc<S> {
+    type Handle<T: Send + 'static> = S::Handle<T>;
+
+    fn spawn<Fut>(&self, fut: Fut) -> Self::Handle<Fut::Output>
+    where
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static,
+    {
+        (**self).spawn(fut)
+    }
+}
+
+pin_project! {
+    /// Future turning the panics of the inner future into a [`SpawnError`]
+    struct CatchPanic<Fut> {
+        #[pin]
+        fut: Fut,
+    }
+}
+
+impl<Fut: Future> Future for CatchPanic<Fut> {
+    type Output = Result<Fut::Output, SpawnError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let fut = self.project().fut;
+
+        match std::panic::catch_unwind(AssertUnwindSafe(|| fut.poll(cx))) {
+            Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(payload) => Poll::Ready(Err(SpawnError::panicked(payload))),
+        }
+    }
+}
+
+#[cfg(feature = "thread-pool")]
+impl Spawner for futures::executor::ThreadPool {
+    type Handle<T: Send + 'static> = futures::future::RemoteHandle<Result<T, SpawnError>>;
+
+    fn spawn<Fut>(&self, fut: Fut) -> Self::Handle<Fut::Output>
+    where
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static,
+    {
+        use futures::FutureExt as _;
+
+        let (remote, handle) = CatchPanic { fut }.remote_handle();
+        self.spawn_ok(remote);
+        handle
+    }
+}
+
+#[cfg(feature = "smol-executor")]
+impl Spawner for async_executor::Executor<'static> {
+    type Handle<T: Send + 'static> = SmolHandle<T>;
+
+    fn spawn<Fut>(&self, fut: Fut) -> Self::Handle<Fut::Output>
+    where
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static,
+    {
+        SmolHandle {
+            task: self.spawn(CatchPanic { fut }).fallible(),
+        }
+    }
+}
+
+/// Handle of a task spawned on an [`async_executor::Executor`]. The task is cancelled on drop
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use async_executor::Executor;
+/// use streamies::spawner::{SpawnError, Spawner};
+///
+/// let executor = Executor::new();
+/// let task = Spawner::spawn(&executor, async { panic!("oh no") });
+/// let output: Result<(), _> = executor.run(task).await;
+///
+/// assert_eq!(output, Err(SpawnError::Panicked { message: Some("oh no".into()) }));
+/// # });
+/// ```
+#[cfg(feature = "smol-executor")]
+#[derive(Debug)]
+pub struct SmolHandle<T> {
+    task: async_executor::FallibleTask<Result<T, SpawnError>>,
+}
+
+#[cfg(feature = "smol-executor")]
+impl<T> Future for SmolHandle<T> {
+    type Output = Result<T, SpawnError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // The task only returns `None` if the executor got dropped
+        let output = core::task::ready!(Pin::new(&mut self.task).poll(cx));
+        Poll::Ready(output.unwrap_or(Err(SpawnError::Cancelled)))
+    }
+}
+
+/// [`Spawner`] running the tasks on the current tokio runtime, with [`tokio::spawn`]
+///
+/// ```
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// use streamies::spawner::{SpawnError, Spawner, TokioSpawner};
+///
+/// assert_eq!(TokioSpawner.spawn(async { 1 + 1 }).await, Ok(2));
+///
+/// let panicked = TokioSpawner.spawn(async { panic!("oh no") }).await;
+/// assert_eq!(panicked, Err::<(), _>(SpawnError::Panicked { message: Some("oh no".into()) }));
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TokioSpawner;
+
+#[cfg(feature = "tokio")]
+impl Spawner for TokioSpawner {
+    type Handle<T: Send + 'static> = TokioHandle<T>;
+
+    fn spawn<Fut>(&self, fut: Fut) -> Self::Handle<Fut::Output>
+    where
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static,
+    {
+        TokioHandle {
+            handle: tokio::spawn(CatchPanic { fut }),
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Spawner for tokio::runtime::Handle {
+    type Handle<T: Send + 'static> = TokioHandle<T>;
+
+    fn spawn<Fut>(&self, fut: Fut) -> Self::Handle<Fut::Output>
+    where
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static,
+    {
+        TokioHandle {
+            handle: self.spawn(CatchPanic { fut }),
+        }
+    }
+}
+
+/// Handle of a task spawned on tokio. Unlike a [`JoinHandle`](tokio::task::JoinHandle), the task is aborted on drop
+#[cfg(feature = "tokio")]
+#[derive(Debug)]
+pub struct TokioHandle<T> {
+    handle: tokio::task::JoinHandle<Result<T, SpawnError>>,
+}
+
+#[cfg(feature = "tokio")]
+impl<T> Future for TokioHandle<T> {
+    type Output = Result<T, SpawnError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Panics are already caught, so a join error is a cancellation
+        let output = core::task::ready!(Pin::new(&mut self.handle).poll(cx));
+        Poll::Ready(output.unwrap_or(Err(SpawnError::Cancelled)))
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<T> Drop for TokioHandle<T> {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
@@ -0,0 +1,67 @@
+use alloc::collections::BTreeMap;
+use core::future::Future;
+use core::mem::take;
+use core::pin::Pin;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::future::FusedFuture;
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`collect_btreemap`](crate::Streamies::collect_btreemap) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct CollectBTreeMap<St, K, V> {
+        #[pin]
+        stream: St,
+        map: BTreeMap<K, V>,
+    }
+}
+
+impl<St, K, V> CollectBTreeMap<St, K, V>
+where
+    St: Stream<Item = (K, V)>,
+    K: Ord,
+{
+    pub(crate) fn new(stream: St) -> Self {
+        Self {
+            stream,
+            map: Default::default(),
+        }
+    }
+}
+
+impl<St, K, V> FusedFuture for CollectBTreeMap<St, K, V>
+where
+    St: FusedStream<Item = (K, V)>,
+    K: Ord,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, K, V> Future for CollectBTreeMap<St, K, V>
+where
+    St: Stream<Item = (K, V)>,
+    K: Ord,
+{
+    type Output = BTreeMap<K, V>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some((key, value)) => {
+                    this.map.insert(key, value);
+                }
+                None => return Poll::Ready(take(this.map)),
+            }
+        }
+    }
+}
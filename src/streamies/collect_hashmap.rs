@@ -0,0 +1,69 @@
+use core::future::Future;
+use core::hash::Hash;
+use core::mem::take;
+use core::pin::Pin;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::future::FusedFuture;
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+use crate::collections::HashMap;
+
+pin_project! {
+    /// Future for the [`collect_hashmap`](crate::Streamies::collect_hashmap) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct CollectHashMap<St, K, V> {
+        #[pin]
+        stream: St,
+        map: HashMap<K, V>,
+    }
+}
+
+impl<St, K, V> CollectHashMap<St, K, V>
+where
+    St: Stream<Item = (K, V)>,
+    K: Eq + Hash,
+{
+    pub(crate) fn new(stream: St) -> Self {
+        Self {
+            stream,
+            map: Default::default(),
+        }
+    }
+}
+
+impl<St, K, V> FusedFuture for CollectHashMap<St, K, V>
+where
+    St: FusedStream<Item = (K, V)>,
+    K: Eq + Hash,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, K, V> Future for CollectHashMap<St, K, V>
+where
+    St: Stream<Item = (K, V)>,
+    K: Eq + Hash,
+{
+    type Output = HashMap<K, V>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some((key, value)) => {
+                    this.map.insert(key, value);
+                }
+                None => return Poll::Ready(take(this.map)),
+            }
+        }
+    }
+}
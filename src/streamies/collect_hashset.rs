@@ -0,0 +1,72 @@
+use core::future::Future;
+use core::hash::Hash;
+use core::mem::take;
+use core::pin::Pin;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::future::FusedFuture;
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+use crate::collections::HashSet;
+
+pin_project! {
+    /// Future for the [`collect_hashset`](crate::Streamies::collect_hashset) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct CollectHashSet<St>
+    where
+        St: Stream,
+    {
+        #[pin]
+        stream: St,
+        set: HashSet<St::Item>,
+    }
+}
+
+impl<St> CollectHashSet<St>
+where
+    St: Stream,
+    St::Item: Eq + Hash,
+{
+    pub(crate) fn new(stream: St) -> Self {
+        Self {
+            stream,
+            set: Default::default(),
+        }
+    }
+}
+
+impl<St> FusedFuture for CollectHashSet<St>
+where
+    St: FusedStream,
+    St::Item: Eq + Hash,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St> Future for CollectHashSet<St>
+where
+    St: Stream,
+    St::Item: Eq + Hash,
+{
+    type Output = HashSet<St::Item>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    this.set.insert(item);
+                }
+                None => return Poll::Ready(take(this.set)),
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use alloc::string::String;
+use core::future::Future;
+use core::mem::take;
+use core::pin::Pin;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::future::FusedFuture;
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`collect_string`](crate::Streamies::collect_string) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct CollectString<St> {
+        #[pin]
+        stream: St,
+        string: String,
+    }
+}
+
+impl<St> CollectString<St>
+where
+    St: Stream,
+    String: Extend<St::Item>,
+{
+    pub(crate) fn new(stream: St) -> Self {
+        Self {
+            stream,
+            string: Default::default(),
+        }
+    }
+}
+
+impl<St> FusedFuture for CollectString<St>
+where
+    St: FusedStream,
+    String: Extend<St::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St> Future for CollectString<St>
+where
+    St: Stream,
+    String: Extend<St::Item>,
+{
+    type Output = String;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => this.string.extend(Some(item)),
+                None => return Poll::Ready(take(this.string)),
+            }
+        }
+    }
+}
@@ -1,15 +1,18 @@
+use alloc::vec::Vec;
 use core::future::Future;
+use core::mem::take;
 use core::pin::Pin;
+use core::task::ready;
 use core::task::Context;
 use core::task::Poll;
 
-use futures::future::FusedFuture;
-use futures::stream::Collect;
-use futures::stream::FusedStream;
-use futures::Stream;
+use futures_core::future::FusedFuture;
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
 use pin_project_lite::pin_project;
 
 pin_project! {
+    /// Future for the [`collect_vec`](crate::Streamies::collect_vec) method.
     #[derive(Debug)]
     #[must_use = "futures do nothing unless you `.await` or poll them"]
     pub struct CollectVec<St>
@@ -17,7 +20,8 @@ pin_project! {
         St: Stream
     {
         #[pin]
-        collect: Collect<St, Vec<St::Item>>,
+        stream: St,
+        items: Vec<St::Item>,
     }
 }
 
@@ -25,8 +29,19 @@ impl<St> CollectVec<St>
 where
     St: Stream,
 {
-    pub fn new(collect: Collect<St, Vec<St::Item>>) -> Self {
-        Self { collect }
+    /// Create a future collecting `stream` into a vec
+    pub fn new(stream: St) -> Self {
+        Self {
+            stream,
+            items: Vec::new(),
+        }
+    }
+
+    pub(crate) fn with_capacity(stream: St, capacity: usize) -> Self {
+        Self {
+            stream,
+            items: Vec::with_capacity(capacity),
+        }
     }
 }
 
@@ -35,7 +50,7 @@ where
     St: FusedStream,
 {
     fn is_terminated(&self) -> bool {
-        self.collect.is_terminated()
+        self.stream.is_terminated()
     }
 }
 
@@ -45,8 +60,14 @@ where
 {
     type Output = Vec<St::Item>;
 
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let this = self.as_mut().project();
-        this.collect.poll(cx)
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => this.items.push(item),
+                None => return Poll::Ready(take(this.items)),
+            }
+        }
     }
 }
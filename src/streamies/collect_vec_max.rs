@@ -0,0 +1,126 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::Future;
+use core::mem::take;
+use core::pin::Pin;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::future::FusedFuture;
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+/// Error returned when a bounded collection received more than its limit.
+///
+/// It carries every item received until then, including the one that got over the limit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectLimitExceeded<T> {
+    limit: usize,
+    items: Vec<T>,
+}
+
+impl<T> CollectLimitExceeded<T> {
+    pub(crate) fn new(limit: usize, items: Vec<T>) -> Self {
+        Self { limit, items }
+    }
+
+    /// The limit that got exceeded
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// The items received before the limit got exceeded, including the one that exceeded it
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Take the items received before the limit got exceeded, including the one that exceeded it
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+}
+
+impl<T> fmt::Display for CollectLimitExceeded<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the collection limit of {} got exceeded", self.limit)
+    }
+}
+
+impl<T: fmt::Debug> core::error::Error for CollectLimitExceeded<T> {}
+
+pin_project! {
+    /// Future for the [`collect_vec_max`](crate::Streamies::collect_vec_max) and
+    /// [`collect_vec_max_weight`](crate::Streamies::collect_vec_max_weight) methods.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct CollectVecMax<St, F>
+    where
+        St: Stream
+    {
+        #[pin]
+        stream: St,
+        items: Vec<St::Item>,
+        weight: usize,
+        limit: usize,
+        weight_fn: F,
+        done: bool,
+    }
+}
+
+impl<St, F> CollectVecMax<St, F>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> usize,
+{
+    pub(crate) fn new(stream: St, limit: usize, weight_fn: F) -> Self {
+        Self {
+            stream,
+            items: Vec::new(),
+            weight: 0,
+            limit,
+            weight_fn,
+            done: false,
+        }
+    }
+}
+
+impl<St, F> FusedFuture for CollectVecMax<St, F>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> usize,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St, F> Future for CollectVecMax<St, F>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> usize,
+{
+    type Output = Result<Vec<St::Item>, CollectLimitExceeded<St::Item>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            let Some(item) = ready!(this.stream.as_mut().poll_next(cx)) else {
+                *this.done = true;
+                return Poll::Ready(Ok(take(this.items)));
+            };
+
+            *this.weight = this.weight.saturating_add((this.weight_fn)(&item));
+            this.items.push(item);
+
+            if this.weight > this.limit {
+                *this.done = true;
+                return Poll::Ready(Err(CollectLimitExceeded::new(
+                    *this.limit,
+                    take(this.items),
+                )));
+            }
+        }
+    }
+}
@@ -1,11 +1,11 @@
 use core::num::NonZeroUsize;
 use core::pin::Pin;
+use core::task::ready;
 use core::task::Context;
 use core::task::Poll;
 
-use futures::ready;
-use futures::stream::FusedStream;
-use futures::Stream;
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
 use pin_project_lite::pin_project;
 
 pin_project! {
@@ -31,7 +31,7 @@ where
     St1: Stream,
     St2: Stream<Item = St1::Item>,
 {
-    pub(super) fn new(
+    pub(crate) fn new(
         stream1: St1,
         stream2: St2,
         first_nb_ele: usize,
@@ -1,18 +1,58 @@
 use core::hash::Hash;
 
-use futures::Stream;
-use futures::StreamExt;
+use alloc::string::String;
+use core::future::Future;
 
+use futures_core::Stream;
+
+#[cfg(feature = "std")]
+use crate::spawner::Spawner;
+
+pub use crate::collect_btreemap::CollectBTreeMap;
+pub use crate::collect_hashmap::CollectHashMap;
+pub use crate::collect_hashset::CollectHashSet;
+pub use crate::collect_string::CollectString;
+pub use crate::collect_vec_max::CollectLimitExceeded;
+pub use crate::collect_vec_max::CollectVecMax;
 pub use crate::unique::Unique;
 pub use crate::unique_by::UniqueBy;
 pub use collect_vec::CollectVec;
 pub use merge_round_robin::MergeRoundRobin;
+pub use par_map_ordered::ParMapMetrics;
+pub use par_map_ordered::ParMapOrdered;
+#[cfg(feature = "std")]
+pub use par_map_spawned::ParMapSpawned;
+pub use reorder_by_seq::ReorderBySeq;
+pub use reorder_by_seq::SeqEvent;
 
+pub mod collect_btreemap;
+pub mod collect_hashmap;
+pub mod collect_hashset;
+pub mod collect_string;
 pub mod collect_vec;
+pub mod collect_vec_max;
 pub mod merge_round_robin;
+pub mod par_map_ordered;
+#[cfg(feature = "std")]
+pub mod par_map_spawned;
+pub mod reorder_by_seq;
 pub mod unique;
 pub mod unique_by;
 
+/// Extension trait for [`Stream`]s.
+///
+/// It is implemented on top of [`futures_core`]'s `Stream` trait, which is shared by both `futures` and `futures_lite`.
+/// This means the same adaptors are available whichever backend feature is enabled.
+///
+/// ```
+/// # futures_lite::future::block_on(async {
+/// use streamies::Streamies as _;
+///
+/// let from_futures = futures::stream::iter(vec![1, 2, 3]).collect_vec().await;
+/// let from_futures_lite = futures_lite::stream::iter(vec![1, 2, 3]).collect_vec().await;
+/// assert_eq!(from_futures, from_futures_lite);
+/// # });
+/// ```
 pub trait Streamies: Stream {
     /// Merge two streams into one, allowing a custom round robin policy
     ///
@@ -72,7 +112,321 @@ pub trait Streamies: Stream {
     where
         Self: Sized,
     {
-        CollectVec::new(self.collect())
+        CollectVec::new(self)
+    }
+
+    /// Collect the stream into a vec, preallocated with the lower bound of the stream's [`size_hint`](Stream::size_hint).
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use streamies::Streamies as _;
+    ///
+    /// let stream = stream::iter(vec![1, 2, 3]);
+    ///
+    /// let result = stream.collect_vec_with_capacity().await;
+    /// assert_eq!(result, vec![1, 2, 3]);
+    /// assert!(result.capacity() >= 3);
+    /// # });
+    /// ```
+    fn collect_vec_with_capacity(self) -> CollectVec<Self>
+    where
+        Self: Sized,
+    {
+        let (lower, _) = self.size_hint();
+        CollectVec::with_capacity(self, lower)
+    }
+
+    /// Collect the stream into a vec, failing if the stream yields more than `max` items.
+    ///
+    /// This protects against unbounded streams filling up the memory. Once the limit is exceeded,
+    /// the stream isn't polled anymore, and the items received so far are returned in the [`CollectLimitExceeded`] error.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use streamies::Streamies as _;
+    ///
+    /// let result = stream::iter(vec![1, 2, 3]).collect_vec_max(3).await;
+    /// assert_eq!(result, Ok(vec![1, 2, 3]));
+    ///
+    /// let err = stream::repeat(1).collect_vec_max(3).await.unwrap_err();
+    /// assert_eq!(err.limit(), 3);
+    /// assert_eq!(err.into_items(), vec![1, 1, 1, 1]);
+    /// # });
+    /// ```
+    fn collect_vec_max(self, max: usize) -> CollectVecMax<Self, fn(&Self::Item) -> usize>
+    where
+        Self: Sized,
+    {
+        CollectVecMax::new(self, max, |_| 1)
+    }
+
+    /// Collect the stream into a vec, failing if the total weight of the items is more than `max_weight`.
+    ///
+    /// The weight of each item is computed by `weight_fn`, which allows limiting the collection by bytes instead of item count.
+    /// Once the limit is exceeded, the stream isn't polled anymore, and the items received so far are returned
+    /// in the [`CollectLimitExceeded`] error.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use streamies::Streamies as _;
+    ///
+    /// let pages = vec!["Hello".to_string(), " ".to_string(), "there!".to_string()];
+    ///
+    /// let result = stream::iter(pages.clone()).collect_vec_max_weight(12, |s| s.len()).await;
+    /// assert_eq!(result.unwrap().len(), 3);
+    ///
+    /// let err = stream::iter(pages).collect_vec_max_weight(8, |s| s.len()).await.unwrap_err();
+    /// assert_eq!(err.items().len(), 3);
+    /// # });
+    /// ```
+    fn collect_vec_max_weight<F>(self, max_weight: usize, weight_fn: F) -> CollectVecMax<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> usize,
+    {
+        CollectVecMax::new(self, max_weight, weight_fn)
+    }
+
+    /// Collect a stream of key-value pairs into a hashmap. Later values overwrite the earlier ones with the same key.
+    ///
+    /// Without the `std` feature, the map is a [`hashbrown`](https://docs.rs/hashbrown) map.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use streamies::Streamies as _;
+    ///
+    /// let stream = stream::iter(vec![("a", 1), ("b", 2), ("a", 3)]);
+    ///
+    /// let result = stream.collect_hashmap().await;
+    /// assert_eq!(result.len(), 2);
+    /// assert_eq!(result["a"], 3);
+    /// assert_eq!(result["b"], 2);
+    /// # });
+    /// ```
+    fn collect_hashmap<K, V>(self) -> CollectHashMap<Self, K, V>
+    where
+        Self: Stream<Item = (K, V)> + Sized,
+        K: Eq + Hash,
+    {
+        CollectHashMap::new(self)
+    }
+
+    /// Collect the stream into a hashset.
+    ///
+    /// Without the `std` feature, the set is a [`hashbrown`](https://docs.rs/hashbrown) set.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use streamies::Streamies as _;
+    ///
+    /// let stream = stream::iter(vec![1, 2, 1]);
+    ///
+    /// let result = stream.collect_hashset().await;
+    /// assert_eq!(result.len(), 2);
+    /// assert!(result.contains(&1));
+    /// assert!(result.contains(&2));
+    /// # });
+    /// ```
+    fn collect_hashset(self) -> CollectHashSet<Self>
+    where
+        Self: Sized,
+        Self::Item: Eq + Hash,
+    {
+        CollectHashSet::new(self)
+    }
+
+    /// Collect a stream of key-value pairs into a btreemap. Later values overwrite the earlier ones with the same key.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use std::collections::BTreeMap;
+    /// use futures::stream;
+    /// use streamies::Streamies as _;
+    ///
+    /// let stream = stream::iter(vec![("b", 2), ("a", 1), ("b", 3)]);
+    ///
+    /// let result = stream.collect_btreemap().await;
+    /// assert_eq!(result, BTreeMap::from([("a", 1), ("b", 3)]));
+    /// # });
+    /// ```
+    fn collect_btreemap<K, V>(self) -> CollectBTreeMap<Self, K, V>
+    where
+        Self: Stream<Item = (K, V)> + Sized,
+        K: Ord,
+    {
+        CollectBTreeMap::new(self)
+    }
+
+    /// Collect the stream into a string. Any item that can extend a `String` is accepted (`char`, `&str`, `String`...)
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use streamies::Streamies as _;
+    ///
+    /// let stream = stream::iter(vec!["Hello", " ", "there!"]);
+    /// assert_eq!(stream.collect_string().await, "Hello there!");
+    ///
+    /// let stream = stream::iter(vec!['a', 'b', 'c']);
+    /// assert_eq!(stream.collect_string().await, "abc");
+    /// # });
+    /// ```
+    fn collect_string(self) -> CollectString<Self>
+    where
+        Self: Sized,
+        String: Extend<Self::Item>,
+    {
+        CollectString::new(self)
+    }
+
+    /// Run an async function on each item, with up to `concurrency` futures running at once, and yield their outputs in order.
+    ///
+    /// Unlike [`buffered`](https://docs.rs/futures/latest/futures/stream/trait.StreamExt.html#method.buffered), a slow future
+    /// doesn't stop the next ones from starting: the outputs that complete early wait in a reorder buffer of `reorder_capacity` items.
+    /// Once it is full, no new futures are started until the head of the line completes.
+    ///
+    /// The stream counts how often this happens in its [`metrics`](ParMapOrdered::metrics).
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `concurrency` or `reorder_capacity` is zero.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use core::task::Poll;
+    /// use futures::stream::{self, StreamExt};
+    /// use futures::future;
+    /// use streamies::Streamies as _;
+    ///
+    /// // Stays pending for `polls` polls
+    /// async fn slow(polls: usize) {
+    ///     let mut left = polls;
+    ///     future::poll_fn(|cx| {
+    ///         if left == 0 {
+    ///             return Poll::Ready(());
+    ///         }
+    ///         left -= 1;
+    ///         cx.waker().wake_by_ref();
+    ///         Poll::Pending
+    ///     })
+    ///     .await
+    /// }
+    ///
+    /// let mut stream = stream::iter(vec![3, 0, 0, 1]).par_map_ordered(2, 4, |polls| async move {
+    ///     slow(polls).await;
+    ///     polls
+    /// });
+    ///
+    /// assert_eq!((&mut stream).collect_vec().await, vec![3, 0, 0, 1]);
+    /// assert!(stream.metrics().head_of_line_blocks > 0); // The first item held the next ones back
+    /// # });
+    /// ```
+    fn par_map_ordered<F, Fut>(
+        self,
+        concurrency: usize,
+        reorder_capacity: usize,
+        f: F,
+    ) -> ParMapOrdered<Self, F, Fut>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future,
+    {
+        ParMapOrdered::new(self, concurrency, reorder_capacity, f)
+    }
+
+    /// Run an async function on each item in tasks spawned on `spawner`, with up to `limit` tasks running at once,
+    /// and yield their outputs in order.
+    ///
+    /// Unlike [`par_map_ordered`](Streamies::par_map_ordered), the futures run outside of the polling task, so they can use
+    /// the other threads of a multi-threaded executor. A task that panics yields a [`SpawnError`](crate::spawner::SpawnError)
+    /// instead of bringing down the stream. Dropping the stream cancels the running tasks.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `limit` is zero.
+    ///
+    /// ```
+    /// # #[cfg(feature = "tokio")]
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use futures::stream;
+    /// use streamies::spawner::{SpawnError, TokioSpawner};
+    /// use streamies::Streamies as _;
+    ///
+    /// let results = stream::iter(vec![1, 2, 0, 4])
+    ///     .par_map_spawned(2, TokioSpawner, |n| async move { 12 / n })
+    ///     .collect_vec()
+    ///     .await;
+    ///
+    /// assert_eq!(results[..2], [Ok(12), Ok(6)]);
+    /// assert!(matches!(results[2], Err(SpawnError::Panicked { .. })));
+    /// assert_eq!(results[3], Ok(3));
+    /// # }
+    /// # #[cfg(not(feature = "tokio"))]
+    /// # fn main() {}
+    /// ```
+    #[cfg(feature = "std")]
+    fn par_map_spawned<S, F, Fut>(
+        self,
+        limit: usize,
+        spawner: S,
+        f: F,
+    ) -> ParMapSpawned<Self, S, F, Fut>
+    where
+        Self: Sized,
+        S: Spawner,
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static,
+    {
+        ParMapSpawned::new(self, limit, spawner, f)
+    }
+
+    /// Restore the order of items carrying a sequence number, given by `seq_fn`.
+    ///
+    /// The sequence starts at 0, or at the number given to [`ReorderBySeq::with_start`]. Items arriving early are buffered until the missing ones
+    /// arrive. The stream stops waiting for a missing sequence number, and yields a [`SeqEvent::Gap`], if:
+    /// - a buffered item is more than `max_gap` numbers ahead of it,
+    /// - more than `max_buffer` items are buffered,
+    /// - or the stream ended.
+    ///
+    /// Items whose sequence number was already received or skipped are yielded as [`SeqEvent::Duplicate`].
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use streamies::{SeqEvent, Streamies as _};
+    ///
+    /// let messages = vec![(1, "a"), (3, "c"), (2, "b"), (2, "b"), (6, "f"), (4, "d")];
+    /// let events = stream::iter(messages)
+    ///     .reorder_by_seq(|(seq, _)| *seq, 10, 2)
+    ///     .with_start(1)
+    ///     .collect_vec()
+    ///     .await;
+    ///
+    /// assert_eq!(events, vec![
+    ///     SeqEvent::Item((1, "a")),
+    ///     SeqEvent::Item((2, "b")),
+    ///     SeqEvent::Item((3, "c")),
+    ///     SeqEvent::Duplicate { seq: 2, item: (2, "b") },
+    ///     SeqEvent::Item((4, "d")),
+    ///     SeqEvent::Gap { missing: 5..6 }, // The stream ended without 5
+    ///     SeqEvent::Item((6, "f")),
+    /// ]);
+    /// # });
+    /// ```
+    fn reorder_by_seq<F>(self, seq_fn: F, max_gap: u64, max_buffer: usize) -> ReorderBySeq<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> u64,
+    {
+        ReorderBySeq::new(self, seq_fn, max_gap, max_buffer)
     }
 
     /// Return an stream adaptor that filters out elements that have
@@ -98,7 +452,7 @@ pub trait Streamies: Stream {
     /// ```
     fn unique_by<F, V>(self, f: F) -> UniqueBy<Self, V, F>
     where
-        Self: Stream + StreamExt + Sized,
+        Self: Sized,
         V: Eq + Hash,
         F: FnMut(&Self::Item) -> V,
     {
@@ -0,0 +1,200 @@
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+/// Counters of a [`ParMapOrdered`] stream, to tune its concurrency and reorder capacity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ParMapMetrics {
+    /// The number of futures that completed while an earlier one was still running,
+    /// so their output had to wait in the reorder buffer
+    pub head_of_line_blocks: usize,
+    /// The number of polls where no new future could start because the reorder buffer was full
+    pub backpressure_stalls: usize,
+    /// The largest number of outputs held in the reorder buffer at once
+    pub max_reordered: usize,
+}
+
+/// An item of the stream, waiting for its turn to be yielded
+enum Slot<Fut: Future> {
+    Running(Pin<Box<Fut>>),
+    Done(Fut::Output),
+}
+
+pin_project! {
+    /// Stream for the [`par_map_ordered`](crate::Streamies::par_map_ordered) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ParMapOrdered<St, F, Fut>
+    where
+        Fut: Future,
+    {
+        #[pin]
+        stream: St,
+        slots: VecDeque<Slot<Fut>>,
+        running: usize,
+        concurrency: usize,
+        reorder_capacity: usize,
+        stream_done: bool,
+        metrics: ParMapMetrics,
+        f: F,
+    }
+}
+
+impl<St, F, Fut> ParMapOrdered<St, F, Fut>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Fut,
+    Fut: Future,
+{
+    pub(crate) fn new(stream: St, concurrency: usize, reorder_capacity: usize, f: F) -> Self {
+        assert!(concurrency > 0, "the concurrency must be greater than zero");
+        assert!(
+            reorder_capacity > 0,
+            "the reorder capacity must be greater than zero"
+        );
+
+        Self {
+            stream,
+            slots: VecDeque::with_capacity(concurrency),
+            running: 0,
+            concurrency,
+            reorder_capacity,
+            stream_done: false,
+            metrics: ParMapMetrics::default(),
+            f,
+        }
+    }
+
+    /// The counters of the stream so far
+    pub fn metrics(&self) -> ParMapMetrics {
+        self.metrics
+    }
+}
+
+impl<St, F, Fut> FusedStream for ParMapOrdered<St, F, Fut>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Fut,
+    Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream_done && self.slots.is_empty()
+    }
+}
+
+impl<St, F, Fut> Stream for ParMapOrdered<St, F, Fut>
+where
+    St: Stream,
+    F: FnMut(St::Item) -> Fut,
+    Fut: Future,
+{
+    type Item = Fut::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            // The outputs waiting behind the head are the reorder buffer
+            let reordered = this
+                .slots
+                .iter()
+                .skip(1)
+                .filter(|slot| matches!(slot, Slot::Done(_)))
+                .count();
+
+            while !*this.stream_done && *this.running < *this.concurrency {
+                if reordered >= *this.reorder_capacity {
+                    this.metrics.backpressure_stalls += 1;
+                    break;
+                }
+
+                match this.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(item)) => {
+                        let fut = (this.f)(item);
+                        this.slots.push_back(Slot::Running(Box::pin(fut)));
+                        *this.running += 1;
+                    }
+                    Poll::Ready(None) => *this.stream_done = true,
+                    Poll::Pending => break,
+                }
+            }
+
+            let mut completed = false;
+            let mut head_running = false;
+            for (index, slot) in this.slots.iter_mut().enumerate() {
+                if let Slot::Running(fut) = slot {
+                    if let Poll::Ready(output) = fut.as_mut().poll(cx) {
+                        *slot = Slot::Done(output);
+                        *this.running -= 1;
+                        completed = true;
+
+                        if head_running {
+                            this.metrics.head_of_line_blocks += 1;
+                        }
+                    } else if index == 0 {
+                        head_running = true;
+                    }
+                }
+            }
+
+            let reordered = this
+                .slots
+                .iter()
+                .skip(1)
+                .filter(|slot| matches!(slot, Slot::Done(_)))
+                .count();
+            this.metrics.max_reordered = this.metrics.max_reordered.max(reordered);
+
+            if let Some(Slot::Done(_)) = this.slots.front() {
+                if let Some(Slot::Done(output)) = this.slots.pop_front() {
+                    return Poll::Ready(Some(output));
+                }
+            }
+
+            if this.slots.is_empty() && *this.stream_done {
+                return Poll::Ready(None);
+            }
+
+            // Completed futures made room for new ones, which need a first poll
+            if !completed || *this.stream_done {
+                return Poll::Pending;
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.stream_done {
+            return (self.slots.len(), Some(self.slots.len()));
+        }
+
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(self.slots.len()),
+            upper.and_then(|upper| upper.checked_add(self.slots.len())),
+        )
+    }
+}
+
+impl<St, F, Fut> fmt::Debug for ParMapOrdered<St, F, Fut>
+where
+    St: fmt::Debug,
+    Fut: Future,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParMapOrdered")
+            .field("stream", &self.stream)
+            .field("in_flight", &self.slots.len())
+            .field("running", &self.running)
+            .field("concurrency", &self.concurrency)
+            .field("reorder_capacity", &self.reorder_capacity)
+            .field("metrics", &self.metrics)
+            .finish_non_exhaustive()
+    }
+}
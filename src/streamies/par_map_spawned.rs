@@ -0,0 +1,137 @@
+use alloc::collections::VecDeque;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+use crate::spawner::SpawnError;
+use crate::spawner::Spawner;
+
+pin_project! {
+    /// Stream for the [`par_map_spawned`](crate::Streamies::par_map_spawned) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ParMapSpawned<St, S, F, Fut>
+    where
+        S: Spawner,
+        Fut: Future,
+        Fut::Output: Send,
+        Fut::Output: 'static,
+    {
+        #[pin]
+        stream: St,
+        tasks: VecDeque<S::Handle<Fut::Output>>,
+        limit: usize,
+        stream_done: bool,
+        spawner: S,
+        f: F,
+    }
+}
+
+impl<St, S, F, Fut> ParMapSpawned<St, S, F, Fut>
+where
+    St: Stream,
+    S: Spawner,
+    F: FnMut(St::Item) -> Fut,
+    Fut: Future + Send + 'static,
+    Fut::Output: Send + 'static,
+{
+    pub(crate) fn new(stream: St, limit: usize, spawner: S, f: F) -> Self {
+        assert!(limit > 0, "the limit must be greater than zero");
+
+        Self {
+            stream,
+            tasks: VecDeque::with_capacity(limit),
+            limit,
+            stream_done: false,
+            spawner,
+            f,
+        }
+    }
+}
+
+impl<St, S, F, Fut> FusedStream for ParMapSpawned<St, S, F, Fut>
+where
+    St: Stream,
+    S: Spawner,
+    F: FnMut(St::Item) -> Fut,
+    Fut: Future + Send + 'static,
+    Fut::Output: Send + 'static,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream_done && self.tasks.is_empty()
+    }
+}
+
+impl<St, S, F, Fut> Stream for ParMapSpawned<St, S, F, Fut>
+where
+    St: Stream,
+    S: Spawner,
+    F: FnMut(St::Item) -> Fut,
+    Fut: Future + Send + 'static,
+    Fut::Output: Send + 'static,
+{
+    type Item = Result<Fut::Output, SpawnError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        while !*this.stream_done && this.tasks.len() < *this.limit {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    let task = this.spawner.spawn((this.f)(item));
+                    this.tasks.push_back(task);
+                }
+                Poll::Ready(None) => *this.stream_done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        // The tasks run on their own, so only the head needs polling
+        let Some(head) = this.tasks.front_mut() else {
+            return if *this.stream_done {
+                Poll::Ready(None)
+            } else {
+                Poll::Pending
+            };
+        };
+
+        let output = ready!(Pin::new(head).poll(cx));
+        this.tasks.pop_front();
+        Poll::Ready(Some(output))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.stream_done {
+            return (self.tasks.len(), Some(self.tasks.len()));
+        }
+
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(self.tasks.len()),
+            upper.and_then(|upper| upper.checked_add(self.tasks.len())),
+        )
+    }
+}
+
+impl<St, S, F, Fut> fmt::Debug for ParMapSpawned<St, S, F, Fut>
+where
+    St: fmt::Debug,
+    S: Spawner + fmt::Debug,
+    Fut: Future,
+    Fut::Output: Send + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParMapSpawned")
+            .field("stream", &self.stream)
+            .field("in_flight", &self.tasks.len())
+            .field("limit", &self.limit)
+            .field("spawner", &self.spawner)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,200 @@
+use alloc::collections::BTreeMap;
+use core::fmt;
+use core::ops::Range;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+/// Item of the [`ReorderBySeq`] stream
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SeqEvent<T> {
+    /// The next item in sequence order
+    Item(T),
+
+    /// These sequence numbers never arrived in time, and got skipped
+    Gap {
+        /// The skipped sequence numbers
+        missing: Range<u64>,
+    },
+
+    /// This item's sequence number was already received, or already skipped
+    Duplicate {
+        /// The sequence number of the item
+        seq: u64,
+        /// The duplicated item
+        item: T,
+    },
+}
+
+/// What the [`SeqBuffer`] has to yield next
+pub(crate) enum SeqOutput<T> {
+    Item(T),
+    Gap(Range<u64>),
+}
+
+/// The reordering logic shared by [`ReorderBySeq`] and [`ReorderBySeqOk`](crate::ReorderBySeqOk)
+#[derive(Debug)]
+pub(crate) struct SeqBuffer<T> {
+    buffer: BTreeMap<u64, T>,
+    // The sequence number of the next item to yield
+    next: u64,
+    max_gap: u64,
+    max_buffer: usize,
+}
+
+impl<T> SeqBuffer<T> {
+    pub(crate) fn new(max_gap: u64, max_buffer: usize) -> Self {
+        Self {
+            buffer: BTreeMap::new(),
+            next: 0,
+            max_gap,
+            max_buffer,
+        }
+    }
+
+    /// Set the sequence number of the first item
+    pub(crate) fn set_start(&mut self, start: u64) {
+        self.next = start;
+    }
+
+    /// Store an item until its turn comes, or return it back if it is a duplicate
+    pub(crate) fn push(&mut self, seq: u64, item: T) -> Result<(), T> {
+        if seq < self.next || self.buffer.contains_key(&seq) {
+            return Err(item);
+        }
+
+        self.buffer.insert(seq, item);
+        Ok(())
+    }
+
+    /// Return the next item in order, or skip the missing sequence numbers if waiting for them overflows the limits
+    pub(crate) fn pop(&mut self, stream_done: bool) -> Option<SeqOutput<T>> {
+        let next = self.next;
+
+        if let Some(item) = self.buffer.remove(&next) {
+            self.next = next.saturating_add(1);
+            return Some(SeqOutput::Item(item));
+        }
+
+        let (&first, _) = self.buffer.first_key_value()?;
+        let (&last, _) = self.buffer.last_key_value()?;
+        if stream_done || self.buffer.len() > self.max_buffer || last - next > self.max_gap {
+            self.next = first;
+            return Some(SeqOutput::Gap(next..first));
+        }
+
+        None
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+}
+
+pin_project! {
+    /// Stream for the [`reorder_by_seq`](crate::Streamies::reorder_by_seq) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ReorderBySeq<St, F>
+    where
+        St: Stream,
+    {
+        #[pin]
+        stream: St,
+        buffer: SeqBuffer<St::Item>,
+        stream_done: bool,
+        seq_fn: F,
+    }
+}
+
+impl<St, F> ReorderBySeq<St, F>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> u64,
+{
+    pub(crate) fn new(stream: St, seq_fn: F, max_gap: u64, max_buffer: usize) -> Self {
+        Self {
+            stream,
+            buffer: SeqBuffer::new(max_gap, max_buffer),
+            stream_done: false,
+            seq_fn,
+        }
+    }
+
+    /// Set the sequence number of the first item, 0 by default
+    pub fn with_start(mut self, start: u64) -> Self {
+        self.buffer.set_start(start);
+        self
+    }
+}
+
+impl<St, F> FusedStream for ReorderBySeq<St, F>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> u64,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream_done && self.buffer.is_empty()
+    }
+}
+
+impl<St, F> Stream for ReorderBySeq<St, F>
+where
+    St: Stream,
+    F: FnMut(&St::Item) -> u64,
+{
+    type Item = SeqEvent<St::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            match this.buffer.pop(*this.stream_done) {
+                Some(SeqOutput::Item(item)) => return Poll::Ready(Some(SeqEvent::Item(item))),
+                Some(SeqOutput::Gap(missing)) => {
+                    return Poll::Ready(Some(SeqEvent::Gap { missing }))
+                }
+                None if *this.stream_done => return Poll::Ready(None),
+                None => {}
+            }
+
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    let seq = (this.seq_fn)(&item);
+                    if let Err(item) = this.buffer.push(seq, item) {
+                        return Poll::Ready(Some(SeqEvent::Duplicate { seq, item }));
+                    }
+                }
+                Poll::Ready(None) => *this.stream_done = true,
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Gaps are extra items, so only the lower bound is known
+        let (lower, _) = self.stream.size_hint();
+        (lower.saturating_add(self.buffer.len()), None)
+    }
+}
+
+impl<St, F> fmt::Debug for ReorderBySeq<St, F>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReorderBySeq")
+            .field("stream", &self.stream)
+            .field("buffer", &self.buffer)
+            .field("stream_done", &self.stream_done)
+            .finish_non_exhaustive()
+    }
+}
@@ -2,42 +2,70 @@ use core::hash::Hash;
 use core::pin::Pin;
 use core::task::Context;
 use core::task::Poll;
-use std::collections::hash_map::Entry;
-use std::collections::HashMap;
 
-use futures::stream::FusedStream;
-use futures::Stream;
-use futures::StreamExt;
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
 use pin_project_lite::pin_project;
 
+use crate::budget::Budget;
+use crate::collections::Entry;
+use crate::collections::HashMap;
 use crate::ready_some;
 
 pin_project! {
-    /// Stream for the [`merge_round_robin`](crate::Streamies::merge_round_robin) method.
+    /// Stream for the [`unique`](crate::Streamies::unique) method.
+    ///
+    /// As duplicates are skipped, only the first item is guaranteed to be yielded
+    /// if the inner stream has any.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, FusedStream, Stream, StreamExt};
+    /// use streamies::Streamies as _;
+    ///
+    /// let mut stream = stream::iter(vec![1, 2, 1]).fuse().unique();
+    /// assert_eq!(stream.size_hint(), (1, Some(3)));
+    ///
+    /// assert_eq!(stream.next().await, Some(1));
+    /// assert_eq!(stream.size_hint(), (0, Some(2)));
+    /// assert_eq!(stream.next().await, Some(2));
+    /// assert_eq!(stream.next().await, None);
+    /// assert!(stream.is_terminated());
+    /// assert_eq!(stream.next().await, None);
+    /// # });
+    /// ```
     #[derive(Debug)]
     #[must_use = "streams do nothing unless polled"]
     pub struct Unique<St: Stream> {
         #[pin]
         stream: St,
         used: HashMap<St::Item, ()>,
+        budget: Budget,
     }
 }
 
 impl<St: Stream> Unique<St> {
-    pub(super) fn new(stream: St) -> Self
+    pub(crate) fn new(stream: St) -> Self
     where
         St::Item: Eq + Hash,
     {
         Self {
             stream,
             used: Default::default(),
+            budget: Budget::default(),
         }
     }
+
+    /// Set the cooperative [`Budget`] of the stream
+    pub fn with_budget(mut self, budget: Budget) -> Self {
+        self.budget = budget;
+        self
+    }
 }
 
 impl<St: Stream> FusedStream for Unique<St>
 where
-    St: FusedStream + Stream + StreamExt,
+    St: FusedStream + Stream,
     St::Item: Eq + Hash + Clone,
 {
     fn is_terminated(&self) -> bool {
@@ -47,7 +75,7 @@ where
 
 impl<St> Stream for Unique<St>
 where
-    St: Stream + StreamExt,
+    St: Stream,
     St::Item: Eq + Hash + Clone,
 {
     type Item = St::Item;
@@ -55,6 +83,8 @@ where
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let mut this = self.project();
 
+        let mut remaining = this.budget.start();
+
         loop {
             let item = ready_some!(this.stream.as_mut().poll_next(cx));
 
@@ -63,10 +93,18 @@ where
                 e.insert(());
                 return Poll::Ready(Some(elt));
             }
+
+            // The item was a duplicate. Yield back to the executor if we looped for too long
+            if remaining.tick() {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.stream.size_hint()
+        // Any item after the first one may be a duplicate
+        let (lower, upper) = self.stream.size_hint();
+        (usize::from(lower > 0 && self.used.is_empty()), upper)
     }
 }
@@ -2,30 +2,51 @@ use core::hash::Hash;
 use core::pin::Pin;
 use core::task::Context;
 use core::task::Poll;
-use std::collections::hash_map::Entry;
-use std::collections::HashMap;
 
-use futures::stream::FusedStream;
-use futures::Stream;
-use futures::StreamExt;
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
 use pin_project_lite::pin_project;
 
+use crate::budget::Budget;
+use crate::collections::Entry;
+use crate::collections::HashMap;
 use crate::ready_some;
 
 pin_project! {
-    /// Stream for the [`merge_round_robin`](crate::Streamies::merge_round_robin) method.
+    /// Stream for the [`unique_by`](crate::Streamies::unique_by) method.
+    ///
+    /// As duplicates are skipped, only the first item is guaranteed to be yielded
+    /// if the inner stream has any.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, FusedStream, Stream, StreamExt};
+    /// use streamies::Streamies as _;
+    ///
+    /// let mut stream = stream::iter(vec![1, 2, 1]).fuse().unique_by(|v| *v);
+    /// assert_eq!(stream.size_hint(), (1, Some(3)));
+    ///
+    /// assert_eq!(stream.next().await, Some(1));
+    /// assert_eq!(stream.size_hint(), (0, Some(2)));
+    /// assert_eq!(stream.next().await, Some(2));
+    /// assert_eq!(stream.next().await, None);
+    /// assert!(stream.is_terminated());
+    /// assert_eq!(stream.next().await, None);
+    /// # });
+    /// ```
     #[derive(Debug)]
     #[must_use = "streams do nothing unless polled"]
     pub struct UniqueBy<St: Stream, V, F> {
         #[pin]
         stream: St,
         used: HashMap<V, ()>,
+        budget: Budget,
         f: F,
     }
 }
 
 impl<St: Stream, V, F> UniqueBy<St, V, F> {
-    pub(super) fn new(stream: St, f: F) -> Self
+    pub(crate) fn new(stream: St, f: F) -> Self
     where
         V: Eq + Hash,
 
@@ -34,14 +55,21 @@ impl<St: Stream, V, F> UniqueBy<St, V, F> {
         Self {
             stream,
             used: Default::default(),
+            budget: Budget::default(),
             f,
         }
     }
+
+    /// Set the cooperative [`Budget`] of the stream
+    pub fn with_budget(mut self, budget: Budget) -> Self {
+        self.budget = budget;
+        self
+    }
 }
 
 impl<St: Stream, V, F> FusedStream for UniqueBy<St, V, F>
 where
-    St: FusedStream + Stream + StreamExt,
+    St: FusedStream + Stream,
     V: Eq + Hash,
     F: FnMut(&St::Item) -> V,
 {
@@ -52,7 +80,7 @@ where
 
 impl<St, V, F> Stream for UniqueBy<St, V, F>
 where
-    St: Stream + StreamExt,
+    St: Stream,
     V: Eq + Hash,
     F: FnMut(&St::Item) -> V,
 {
@@ -62,6 +90,8 @@ where
         let mut this = self.project();
         let f = this.f;
 
+        let mut remaining = this.budget.start();
+
         loop {
             let item = ready_some!(this.stream.as_mut().poll_next(cx));
             let key = f(&item);
@@ -70,10 +100,18 @@ where
                 e.insert(());
                 return Poll::Ready(Some(item));
             }
+
+            // The item was a duplicate. Yield back to the executor if we looped for too long
+            if remaining.tick() {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.stream.size_hint()
+        // Any item after the first one may be a duplicate
+        let (lower, upper) = self.stream.size_hint();
+        (usize::from(lower > 0 && self.used.is_empty()), upper)
     }
 }
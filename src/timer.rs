@@ -0,0 +1,274 @@
+use alloc::collections::BTreeMap;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+use core::task::Waker;
+use core::time::Duration;
+
+use crate::lock::Lock;
+
+/// Error returned by the time based adaptors when a deadline elapsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Elapsed(pub(crate) ());
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the deadline has elapsed")
+    }
+}
+
+impl core::error::Error for Elapsed {}
+
+/// A source of sleeps, used by the time based adaptors that don't depend on a specific runtime.
+///
+/// It is implemented for any `Fn(Duration) -> impl Future<Output = ()>`, so a runtime's sleep
+/// function can be used directly. With the `tokio` feature,
+/// [`TokioTimer`](https://docs.rs/streamies/latest/streamies/timer/struct.TokioTimer.html) is also available.
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use core::time::Duration;
+/// use streamies::timer::Timer;
+///
+/// // A timer that never waits, handy in tests
+/// let timer = |_: Duration| core::future::ready(());
+/// timer.sleep(Duration::from_secs(3600)).await;
+/// # });
+/// ```
+pub trait Timer {
+    /// The future returned by [`sleep`](Timer::sleep)
+    type Sleep: Future<Output = ()>;
+
+    /// Return a future completing after `duration`
+    fn sleep(&self, duration: Duration) -> Self::Sleep;
+}
+
+impl<F, Fut> Timer for F
+where
+    F: Fn(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    type Sleep = Fut;
+
+    fn sleep(&self, duration: Duration) -> Self::Sleep {
+        self(duration)
+    }
+}
+
+/// [`Timer`] backed by [`tokio::time::sleep`]
+///
+/// The sleeps must be polled inside a tokio runtime with the time driver enabled. As a [`Clock`], it follows
+/// tokio's clock from the moment it got created, so it also works with [paused time](https://docs.rs/tokio/latest/tokio/time/fn.pause.html).
+///
+/// ```
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// # async fn main() {
+/// use core::time::Duration;
+/// use futures::stream;
+/// use streamies::{Backoff, RetryError, Streamies as _, TokioTimer, TryStreamies as _};
+/// use tokio::time::Instant;
+///
+/// let start = Instant::now();
+/// let backoff = Backoff::new(TokioTimer::new()).base(Duration::from_secs(1));
+///
+/// let stream = stream::iter(vec![Ok::<_, &str>(1)]);
+/// let results = stream.retry_ok_with(3, backoff, |_| async { Err::<i32, _>("down") }).collect_vec().await;
+///
+/// assert_eq!(results, vec![Err(RetryError::Exhausted { attempts: 3, error: "down" })]);
+/// assert_eq!(start.elapsed(), Duration::from_secs(3)); // Waited 1s, then 2s
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TokioTimer {
+    origin: tokio::time::Instant,
+}
+
+#[cfg(feature = "tokio")]
+impl TokioTimer {
+    /// Create a timer, whose clock starts now
+    pub fn new() -> Self {
+        Self {
+            origin: tokio::time::Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Default for TokioTimer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Timer for TokioTimer {
+    type Sleep = tokio::time::Sleep;
+
+    fn sleep(&self, duration: Duration) -> Self::Sleep {
+        tokio::time::sleep(duration)
+    }
+}
+
+/// A monotonic clock, that can also sleep.
+///
+/// [`now`](Clock::now) returns the time elapsed since an arbitrary origin, which must never go backward.
+pub trait Clock: Timer {
+    /// The current time, since the clock's origin
+    fn now(&self) -> Duration;
+}
+
+#[cfg(feature = "tokio")]
+impl Clock for TokioTimer {
+    fn now(&self) -> Duration {
+        tokio::time::Instant::now().saturating_duration_since(self.origin)
+    }
+}
+
+/// A [`Clock`] that only moves when told to, for tests.
+///
+/// Its clones share the same time. Its sleeps stay pending until [`advance`](ManualClock::advance) moves the time
+/// past their deadline, so the outcome of a race against a sleep is up to the test.
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use core::task::Poll;
+/// use core::time::Duration;
+/// use streamies::timer::{Clock, ManualClock, Timer};
+///
+/// let clock = ManualClock::new();
+/// assert_eq!(clock.now(), Duration::ZERO);
+///
+/// let mut sleep = clock.sleep(Duration::from_secs(3));
+/// clock.advance(Duration::from_secs(2));
+/// assert_eq!(futures::poll!(&mut sleep), Poll::Pending);
+///
+/// clock.advance(Duration::from_secs(1));
+/// sleep.await;
+/// assert_eq!(clock.now(), Duration::from_secs(3));
+/// # });
+/// ```
+#[derive(Clone, Default)]
+pub struct ManualClock {
+    shared: Arc<Lock<ManualState>>,
+}
+
+#[derive(Default)]
+struct ManualState {
+    now: Duration,
+    next_id: u64,
+    // The wakers of the pending sleeps, with their deadline
+    sleepers: BTreeMap<u64, (Duration, Waker)>,
+}
+
+impl ManualClock {
+    /// Create a clock at time zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move the time forward by `duration`, waking the sleeps that reached their deadline
+    pub fn advance(&self, duration: Duration) {
+        let mut expired = Vec::new();
+
+        let mut state = self.shared.lock();
+        state.now = state.now.saturating_add(duration);
+        let now = state.now;
+        state.sleepers.retain(|_, (deadline, waker)| {
+            if *deadline > now {
+                return true;
+            }
+
+            expired.push(waker.clone());
+            false
+        });
+        drop(state);
+
+        for waker in expired {
+            waker.wake();
+        }
+    }
+}
+
+impl fmt::Debug for ManualClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ManualClock")
+            .field("now", &self.now())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Timer for ManualClock {
+    type Sleep = ManualSleep;
+
+    fn sleep(&self, duration: Duration) -> Self::Sleep {
+        let mut state = self.shared.lock();
+        let deadline = state.now.saturating_add(duration);
+        let id = state.next_id;
+        state.next_id += 1;
+        drop(state);
+
+        ManualSleep {
+            shared: self.shared.clone(),
+            id,
+            deadline,
+        }
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        self.shared.lock().now
+    }
+}
+
+/// Future returned by the [`sleep`](Timer::sleep) of a [`ManualClock`]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ManualSleep {
+    shared: Arc<Lock<ManualState>>,
+    id: u64,
+    deadline: Duration,
+}
+
+impl Future for ManualSleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.shared.lock();
+
+        if state.now >= self.deadline {
+            state.sleepers.remove(&self.id);
+            return Poll::Ready(());
+        }
+
+        match state.sleepers.get_mut(&self.id) {
+            Some((_, waker)) if waker.will_wake(cx.waker()) => {}
+            Some((_, waker)) => waker.clone_from(cx.waker()),
+            None => {
+                state
+                    .sleepers
+                    .insert(self.id, (self.deadline, cx.waker().clone()));
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+impl Drop for ManualSleep {
+    fn drop(&mut self) {
+        self.shared.lock().sleepers.remove(&self.id);
+    }
+}
+
+impl fmt::Debug for ManualSleep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ManualSleep")
+            .field("deadline", &self.deadline)
+            .finish_non_exhaustive()
+    }
+}
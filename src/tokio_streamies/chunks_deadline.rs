@@ -0,0 +1,120 @@
+use alloc::vec::Vec;
+use core::future::Future;
+use core::mem::take;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+use core::time::Duration;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+use tokio::time::sleep;
+use tokio::time::Sleep;
+
+pin_project! {
+    /// Stream for the [`chunks_deadline`](crate::TokioStreamies::chunks_deadline) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ChunksDeadline<St: Stream> {
+        #[pin]
+        stream: St,
+        items: Vec<St::Item>,
+        cap: usize,
+        #[pin]
+        deadline: Option<Sleep>,
+        duration: Duration,
+        done: bool,
+    }
+}
+
+impl<St: Stream> ChunksDeadline<St> {
+    pub(crate) fn new(stream: St, cap: usize, duration: Duration) -> Self {
+        assert!(cap > 0);
+
+        Self {
+            stream,
+            items: Vec::new(),
+            cap,
+            deadline: None,
+            duration,
+            done: false,
+        }
+    }
+}
+
+impl<St: Stream> FusedStream for ChunksDeadline<St> {
+    fn is_terminated(&self) -> bool {
+        self.done && self.items.is_empty()
+    }
+}
+
+impl<St: Stream> Stream for ChunksDeadline<St> {
+    type Item = Vec<St::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    // The deadline starts with the first item of the chunk
+                    if this.items.is_empty() {
+                        this.items.reserve(*this.cap);
+                        this.deadline.set(Some(sleep(*this.duration)));
+                    }
+
+                    this.items.push(item);
+                    if this.items.len() >= *this.cap {
+                        this.deadline.set(None);
+                        return Poll::Ready(Some(take(this.items)));
+                    }
+                }
+
+                // The stream is finished. Return the last partial chunk if we have one
+                Poll::Ready(None) => {
+                    *this.done = true;
+                    this.deadline.set(None);
+
+                    if this.items.is_empty() {
+                        return Poll::Ready(None);
+                    }
+
+                    return Poll::Ready(Some(take(this.items)));
+                }
+
+                // Nothing to read. Return the partial chunk if we waited for long enough
+                Poll::Pending => {
+                    let Some(deadline) = this.deadline.as_mut().as_pin_mut() else {
+                        return Poll::Pending;
+                    };
+
+                    if deadline.poll(cx).is_pending() {
+                        return Poll::Pending;
+                    }
+
+                    this.deadline.set(None);
+                    return Poll::Ready(Some(take(this.items)));
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        // The deadline may split the items in chunks of any size
+        let buffered = self.items.len();
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(buffered).div_ceil(self.cap),
+            upper.and_then(|upper| upper.checked_add(usize::from(buffered > 0))),
+        )
+    }
+}
@@ -0,0 +1,94 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+use core::time::Duration;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+use tokio::time::sleep;
+use tokio::time::Instant;
+use tokio::time::Sleep;
+
+pub use crate::timer::Elapsed;
+
+pin_project! {
+    /// Stream for the [`item_timeout`](crate::TokioStreamies::item_timeout) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ItemTimeout<St> {
+        #[pin]
+        stream: St,
+        #[pin]
+        deadline: Option<Sleep>,
+        duration: Duration,
+        done: bool,
+    }
+}
+
+impl<St: Stream> ItemTimeout<St> {
+    pub(crate) fn new(stream: St, duration: Duration) -> Self {
+        Self {
+            stream,
+            deadline: None,
+            duration,
+            done: false,
+        }
+    }
+}
+
+impl<St: Stream> FusedStream for ItemTimeout<St> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St: Stream> Stream for ItemTimeout<St> {
+    type Item = Result<St::Item, Elapsed>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        // The timer is created on the first poll, as it requires to be inside the runtime
+        if this.deadline.is_none() {
+            this.deadline.set(Some(sleep(*this.duration)));
+        }
+
+        if let Poll::Ready(item) = this.stream.poll_next(cx) {
+            let Some(item) = item else {
+                *this.done = true;
+                return Poll::Ready(None);
+            };
+
+            if let Some(deadline) = this.deadline.as_pin_mut() {
+                deadline.reset(Instant::now() + *this.duration);
+            }
+
+            return Poll::Ready(Some(Ok(item)));
+        }
+
+        // The stream isn't ready. Let's check if we waited for too long
+        if let Some(mut deadline) = this.deadline.as_pin_mut() {
+            ready!(deadline.as_mut().poll(cx));
+            deadline.reset(Instant::now() + *this.duration);
+        }
+
+        Poll::Ready(Some(Err(Elapsed(()))))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        // Every poll may time out, so there's no upper bound
+        let (lower, _) = self.stream.size_hint();
+        (lower, None)
+    }
+}
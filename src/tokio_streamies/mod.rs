@@ -0,0 +1,132 @@
+use core::time::Duration;
+
+use futures_core::Stream;
+
+pub use crate::tokio_streamies::chunks_deadline::ChunksDeadline;
+pub use crate::tokio_streamies::item_timeout::Elapsed;
+pub use crate::tokio_streamies::item_timeout::ItemTimeout;
+pub use crate::tokio_streamies::pace::Pace;
+pub use crate::tokio_streamies::receivers::ReceiverExt;
+
+pub mod chunks_deadline;
+pub mod item_timeout;
+pub mod pace;
+pub mod receivers;
+
+/// Time based adaptors, backed by [`tokio::time`].
+///
+/// The streams must be polled inside a tokio runtime with the time driver enabled. As they only
+/// rely on [`futures_core`]'s `Stream`, they can be used on any stream, not only `tokio_stream`'s ones.
+pub trait TokioStreamies: Stream {
+    /// Yield an `Err(Elapsed)` each time the stream didn't produce an item within `duration`.
+    ///
+    /// The timer is reset after each yielded item or error, so a stalled stream yields
+    /// an error every `duration`. The stream isn't cancelled by a timeout.
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// use core::time::Duration;
+    /// use futures::stream::{self, StreamExt};
+    /// use streamies::TokioStreamies as _;
+    /// use tokio::time::sleep;
+    ///
+    /// let slow = stream::iter(vec![1, 2]).then(|i| async move {
+    ///     sleep(Duration::from_secs(i * 3)).await;
+    ///     i
+    /// });
+    ///
+    /// let mut stream = Box::pin(slow.item_timeout(Duration::from_secs(5)));
+    /// assert_eq!(stream.next().await, Some(Ok(1)));
+    /// assert!(stream.next().await.unwrap().is_err()); // The second item takes 6 seconds
+    /// assert_eq!(stream.next().await, Some(Ok(2)));
+    /// assert_eq!(stream.next().await, None);
+    /// # }
+    /// ```
+    fn item_timeout(self, duration: Duration) -> ItemTimeout<Self>
+    where
+        Self: Sized,
+    {
+        ItemTimeout::new(self, duration)
+    }
+
+    /// Pace the stream so it yields at most one item per `period`.
+    ///
+    /// The first item is yielded immediately. If the stream is slower than the period,
+    /// the next item is yielded as soon as it is ready, and the period restarts from there.
+    /// The end of the stream is paced like an item.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `period` is zero.
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// use core::time::Duration;
+    /// use futures::stream::{self, StreamExt};
+    /// use streamies::TokioStreamies as _;
+    /// use tokio::time::Instant;
+    ///
+    /// let start = Instant::now();
+    /// let mut stream = stream::iter(vec![1, 2, 3]).pace(Duration::from_secs(1));
+    ///
+    /// assert_eq!(stream.next().await, Some(1));
+    /// assert_eq!(start.elapsed(), Duration::ZERO);
+    /// assert_eq!(stream.next().await, Some(2));
+    /// assert_eq!(start.elapsed(), Duration::from_secs(1));
+    /// assert_eq!(stream.next().await, Some(3));
+    /// assert_eq!(start.elapsed(), Duration::from_secs(2));
+    /// assert_eq!(stream.next().await, None);
+    /// assert_eq!(start.elapsed(), Duration::from_secs(3)); // The end is paced like an item
+    /// assert_eq!(stream.next().await, None);
+    /// assert_eq!(start.elapsed(), Duration::from_secs(3)); // But polling again doesn't wait
+    /// # }
+    /// ```
+    fn pace(self, period: Duration) -> Pace<Self>
+    where
+        Self: Sized,
+    {
+        Pace::new(self, period)
+    }
+
+    /// An adaptor for chunking up items of the stream inside a vector, with a deadline.
+    ///
+    /// At most `capacity` items will get buffered before they're yielded from the returned stream.
+    /// If the chunk isn't full `duration` after its first item got received, the partial chunk is yielded.
+    ///
+    /// If the underlying stream ended and only a partial vector was created, it will be returned.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` is zero.
+    ///
+    /// ```
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// use core::time::Duration;
+    /// use futures::stream::{self, StreamExt};
+    /// use streamies::TokioStreamies as _;
+    /// use tokio::time::sleep;
+    ///
+    /// let stream = stream::iter(vec![(1, 0), (2, 0), (3, 0), (4, 10), (5, 0)]).then(|(i, wait)| async move {
+    ///     sleep(Duration::from_secs(wait)).await;
+    ///     i
+    /// });
+    ///
+    /// let mut stream = Box::pin(stream.chunks_deadline(2, Duration::from_secs(5)));
+    /// assert_eq!(stream.next().await, Some(vec![1, 2]));
+    /// assert_eq!(stream.next().await, Some(vec![3])); // 4 takes too long to arrive
+    /// assert_eq!(stream.next().await, Some(vec![4, 5]));
+    /// assert_eq!(stream.next().await, None);
+    /// # }
+    /// ```
+    fn chunks_deadline(self, capacity: usize, duration: Duration) -> ChunksDeadline<Self>
+    where
+        Self: Sized,
+    {
+        ChunksDeadline::new(self, capacity, duration)
+    }
+}
+
+impl<St: Stream> TokioStreamies for St {}
@@ -0,0 +1,78 @@
+use core::pin::Pin;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+use core::time::Duration;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+use tokio::time::interval;
+use tokio::time::Interval;
+use tokio::time::MissedTickBehavior;
+
+pin_project! {
+    /// Stream for the [`pace`](crate::TokioStreamies::pace) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Pace<St> {
+        #[pin]
+        stream: St,
+        interval: Option<Interval>,
+        period: Duration,
+        ticked: bool,
+    }
+}
+
+impl<St: Stream> Pace<St> {
+    pub(crate) fn new(stream: St, period: Duration) -> Self {
+        assert!(!period.is_zero(), "The period of `pace` must not be zero");
+
+        Self {
+            stream,
+            interval: None,
+            period,
+            ticked: false,
+        }
+    }
+}
+
+impl<St: FusedStream> FusedStream for Pace<St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St: Stream> Stream for Pace<St> {
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        // The interval is created on the first poll, as it requires to be inside the runtime
+        let period = *this.period;
+        let interval = this.interval.get_or_insert_with(|| {
+            let mut interval = interval(period);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            interval
+        });
+
+        // Keep the tick if the stream isn't ready, so the next item doesn't wait another period
+        if !*this.ticked {
+            ready!(interval.poll_tick(cx));
+            *this.ticked = true;
+        }
+
+        // Keep the tick at the end of the stream too, so polling after `None` doesn't wait another period
+        let Some(item) = ready!(this.stream.poll_next(cx)) else {
+            return Poll::Ready(None);
+        };
+
+        *this.ticked = false;
+        Poll::Ready(Some(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
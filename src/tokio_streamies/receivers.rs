@@ -0,0 +1,75 @@
+use tokio::sync::broadcast;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+/// Convert tokio's channel receivers into streams.
+///
+/// The returned streams are [`tokio_stream`]'s wrappers, which implement [`Stream`](futures_core::Stream),
+/// so all the [`Streamies`](crate::Streamies) adaptors can be used on them directly.
+///
+/// ```
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// use streamies::ReceiverExt as _;
+/// use streamies::Streamies as _;
+/// use tokio::sync::mpsc;
+///
+/// let (sender, receiver) = mpsc::channel(4);
+/// sender.send(1).await.unwrap();
+/// sender.send(2).await.unwrap();
+/// drop(sender);
+///
+/// assert_eq!(receiver.into_stream().collect_vec().await, vec![1, 2]);
+/// # });
+/// ```
+pub trait ReceiverExt {
+    type Stream: futures_core::Stream;
+
+    /// Wrap the receiver into a stream
+    fn into_stream(self) -> Self::Stream;
+}
+
+impl<T> ReceiverExt for mpsc::Receiver<T> {
+    type Stream = ReceiverStream<T>;
+
+    fn into_stream(self) -> Self::Stream {
+        ReceiverStream::new(self)
+    }
+}
+
+impl<T> ReceiverExt for mpsc::UnboundedReceiver<T> {
+    type Stream = UnboundedReceiverStream<T>;
+
+    fn into_stream(self) -> Self::Stream {
+        UnboundedReceiverStream::new(self)
+    }
+}
+
+/// Lagging receivers yield a [`BroadcastStreamRecvError`](tokio_stream::wrappers::errors::BroadcastStreamRecvError),
+/// so the stream can be used with [`TryStreamies`](crate::TryStreamies).
+///
+/// ```
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// use streamies::ReceiverExt as _;
+/// use streamies::TryStreamies as _;
+/// use tokio::sync::broadcast;
+///
+/// let (sender, receiver) = broadcast::channel(4);
+/// sender.send(1).unwrap();
+/// sender.send(2).unwrap();
+/// drop(sender);
+///
+/// assert_eq!(receiver.into_stream().try_collect_vec().await, Ok(vec![1, 2]));
+/// # });
+/// ```
+impl<T> ReceiverExt for broadcast::Receiver<T>
+where
+    T: 'static + Clone + Send,
+{
+    type Stream = BroadcastStream<T>;
+
+    fn into_stream(self) -> Self::Stream {
+        BroadcastStream::new(self)
+    }
+}
@@ -0,0 +1,407 @@
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::sync::atomic::AtomicU8;
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::Ordering;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+use core::time::Duration;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
+use pin_project_lite::pin_project;
+
+use crate::timer::Clock;
+use crate::timer::Timer;
+
+/// Error yielded in place of the items received while the circuit is open
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitOpen(());
+
+impl fmt::Display for CircuitOpen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the circuit breaker is open")
+    }
+}
+
+impl core::error::Error for CircuitOpen {}
+
+/// Error of the [`CircuitBreaker`] stream
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitBreakerError<E> {
+    /// The item got short-circuited, as the circuit is open
+    Open(CircuitOpen),
+
+    /// The inner stream yielded an error
+    Upstream(E),
+}
+
+impl<E: fmt::Display> fmt::Display for CircuitBreakerError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Open(err) => err.fmt(f),
+            Self::Upstream(err) => err.fmt(f),
+        }
+    }
+}
+
+impl<E> core::error::Error for CircuitBreakerError<E>
+where
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Open(err) => Some(err),
+            Self::Upstream(err) => Some(err),
+        }
+    }
+}
+
+/// What the [`CircuitBreaker`] does while the circuit is open
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OpenBehavior {
+    /// Keep polling the inner stream, but replace its items with [`CircuitBreakerError::Open`]
+    #[default]
+    ShortCircuit,
+
+    /// Stop polling the inner stream until the cooldown is over
+    Pause,
+}
+
+/// State of a circuit breaker
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CircuitState {
+    /// The items flow through, and their error rate is tracked
+    Closed,
+
+    /// Too many errors got received. The items are short-circuited or paused until the cooldown is over
+    Open,
+
+    /// The cooldown is over, and the next items decide whether the circuit closes or opens again
+    HalfOpen,
+}
+
+impl CircuitState {
+    fn from_u8(state: u8) -> Self {
+        match state {
+            0 => Self::Closed,
+            1 => Self::Open,
+            _ => Self::HalfOpen,
+        }
+    }
+
+    fn to_u8(self) -> u8 {
+        match self {
+            Self::Closed => 0,
+            Self::Open => 1,
+            Self::HalfOpen => 2,
+        }
+    }
+}
+
+/// Configuration of the [`circuit_breaker`](crate::TryStreamies::circuit_breaker) method.
+///
+/// By default, the circuit opens once half of the last 10 items are errors, and half-opens after 30 seconds.
+/// A single success while half-open closes it again.
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig<C> {
+    clock: C,
+    failure_rate: f64,
+    window: usize,
+    min_items: usize,
+    cooldown: Duration,
+    half_open_successes: usize,
+    on_open: OpenBehavior,
+}
+
+impl<C: Clock> CircuitBreakerConfig<C> {
+    /// Create the default configuration, measuring the cooldown with `clock`
+    pub fn new(clock: C) -> Self {
+        Self {
+            clock,
+            failure_rate: 0.5,
+            window: 10,
+            min_items: 10,
+            cooldown: Duration::from_secs(30),
+            half_open_successes: 1,
+            on_open: OpenBehavior::default(),
+        }
+    }
+
+    /// Set the error rate at which the circuit opens
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `failure_rate` isn't in `(0.0, 1.0]`.
+    pub fn failure_rate(mut self, failure_rate: f64) -> Self {
+        assert!(
+            failure_rate > 0.0 && failure_rate <= 1.0,
+            "the failure rate must be in (0.0, 1.0]"
+        );
+        self.failure_rate = failure_rate;
+        self
+    }
+
+    /// Set the number of recent items the error rate is computed on.
+    ///
+    /// The minimum number of items is also lowered to the window if it's bigger
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `window` is zero.
+    pub fn window(mut self, window: usize) -> Self {
+        assert!(window > 0, "the window must hold at least one item");
+        self.window = window;
+        self.min_items = self.min_items.min(window);
+        self
+    }
+
+    /// Set the number of items needed in the window before the error rate is considered
+    pub fn min_items(mut self, min_items: usize) -> Self {
+        self.min_items = min_items.clamp(1, self.window);
+        self
+    }
+
+    /// Set how long the circuit stays open before half-opening
+    pub fn cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Set the number of successes needed while half-open to close the circuit.
+    ///
+    /// The items are evaluated one by one as they come, so while half-open, the first failure opens the circuit
+    /// again, and this many successes in a row close it. No other item is let through in the meantime.
+    pub fn half_open_successes(mut self, successes: usize) -> Self {
+        self.half_open_successes = successes.max(1);
+        self
+    }
+
+    /// Set what happens while the circuit is open
+    pub fn on_open(mut self, on_open: OpenBehavior) -> Self {
+        self.on_open = on_open;
+        self
+    }
+}
+
+/// Counters of a circuit breaker, as seen through its [`CircuitBreakerHandle`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CircuitMetrics {
+    /// The number of `Ok` values that went through
+    pub successes: usize,
+    /// The number of errors that went through
+    pub failures: usize,
+    /// The number of items replaced by [`CircuitBreakerError::Open`]
+    pub rejected: usize,
+    /// The number of times the circuit opened
+    pub opened: usize,
+}
+
+#[derive(Debug, Default)]
+struct Shared {
+    state: AtomicU8,
+    successes: AtomicUsize,
+    failures: AtomicUsize,
+    rejected: AtomicUsize,
+    opened: AtomicUsize,
+}
+
+/// Read-only handle on the state of a [`CircuitBreaker`], for metrics.
+///
+/// It can be cloned and sent to another task, and stays valid after the stream is dropped.
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerHandle {
+    shared: Arc<Shared>,
+}
+
+impl CircuitBreakerHandle {
+    /// The current state of the circuit.
+    ///
+    /// The state only changes while the stream is polled. After the cooldown, it keeps reporting
+    /// [`CircuitState::Open`] until the next poll half-opens the circuit.
+    pub fn state(&self) -> CircuitState {
+        CircuitState::from_u8(self.shared.state.load(Ordering::Acquire))
+    }
+
+    /// The counters of the circuit breaker
+    pub fn metrics(&self) -> CircuitMetrics {
+        CircuitMetrics {
+            successes: self.shared.successes.load(Ordering::Relaxed),
+            failures: self.shared.failures.load(Ordering::Relaxed),
+            rejected: self.shared.rejected.load(Ordering::Relaxed),
+            opened: self.shared.opened.load(Ordering::Relaxed),
+        }
+    }
+}
+
+pin_project! {
+    /// Stream for the [`circuit_breaker`](crate::TryStreamies::circuit_breaker) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct CircuitBreaker<St, C>
+    where
+        C: Timer,
+    {
+        #[pin]
+        stream: St,
+        #[pin]
+        pause: Option<C::Sleep>,
+        config: CircuitBreakerConfig<C>,
+        state: CircuitState,
+        // Outcomes of the last items while closed, `true` being a failure
+        outcomes: VecDeque<bool>,
+        failures: usize,
+        opened_at: Duration,
+        half_open_successes: usize,
+        shared: Arc<Shared>,
+    }
+}
+
+impl<St, C> CircuitBreaker<St, C>
+where
+    St: TryStream,
+    C: Clock,
+{
+    pub(crate) fn new(stream: St, config: CircuitBreakerConfig<C>) -> Self {
+        Self {
+            stream,
+            pause: None,
+            outcomes: VecDeque::with_capacity(config.window),
+            config,
+            state: CircuitState::Closed,
+            failures: 0,
+            opened_at: Duration::ZERO,
+            half_open_successes: 0,
+            shared: Arc::default(),
+        }
+    }
+
+    /// Get a handle on the state and counters of the circuit breaker
+    pub fn handle(&self) -> CircuitBreakerHandle {
+        CircuitBreakerHandle {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<St, C> FusedStream for CircuitBreaker<St, C>
+where
+    St: TryStream + FusedStream,
+    C: Clock,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, C> Stream for CircuitBreaker<St, C>
+where
+    St: TryStream,
+    C: Clock,
+{
+    type Item = Result<St::Ok, CircuitBreakerError<St::Error>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.state == CircuitState::Open {
+            let elapsed = this.config.clock.now().saturating_sub(*this.opened_at);
+
+            if elapsed >= this.config.cooldown {
+                this.pause.set(None);
+                *this.half_open_successes = 0;
+                set_state(this.state, this.shared, CircuitState::HalfOpen);
+            } else if this.config.on_open == OpenBehavior::Pause {
+                if this.pause.is_none() {
+                    let remaining = this.config.cooldown - elapsed;
+                    this.pause.set(Some(this.config.clock.sleep(remaining)));
+                }
+
+                if let Some(pause) = this.pause.as_mut().as_pin_mut() {
+                    ready!(pause.poll(cx));
+                }
+                this.pause.set(None);
+                *this.half_open_successes = 0;
+                set_state(this.state, this.shared, CircuitState::HalfOpen);
+            }
+        }
+
+        let item = match ready!(this.stream.as_mut().try_poll_next(cx)) {
+            Some(item) => item,
+            None => return Poll::Ready(None),
+        };
+
+        if *this.state == CircuitState::Open {
+            this.shared.rejected.fetch_add(1, Ordering::Relaxed);
+            return Poll::Ready(Some(Err(CircuitBreakerError::Open(CircuitOpen(())))));
+        }
+
+        let failed = item.is_err();
+        if failed {
+            this.shared.failures.fetch_add(1, Ordering::Relaxed);
+        } else {
+            this.shared.successes.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let should_open = match *this.state {
+            CircuitState::HalfOpen if failed => true,
+            CircuitState::HalfOpen => {
+                *this.half_open_successes += 1;
+                if *this.half_open_successes >= this.config.half_open_successes {
+                    this.outcomes.clear();
+                    *this.failures = 0;
+                    set_state(this.state, this.shared, CircuitState::Closed);
+                }
+                false
+            }
+            _ => {
+                if this.outcomes.len() == this.config.window
+                    && this.outcomes.pop_front() == Some(true)
+                {
+                    *this.failures -= 1;
+                }
+                this.outcomes.push_back(failed);
+                *this.failures += usize::from(failed);
+
+                let rate = *this.failures as f64 / this.outcomes.len() as f64;
+                this.outcomes.len() >= this.config.min_items && rate >= this.config.failure_rate
+            }
+        };
+
+        if should_open {
+            *this.opened_at = this.config.clock.now();
+            this.shared.opened.fetch_add(1, Ordering::Relaxed);
+            set_state(this.state, this.shared, CircuitState::Open);
+        }
+
+        Poll::Ready(Some(item.map_err(CircuitBreakerError::Upstream)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Items are mapped one to one
+        self.stream.size_hint()
+    }
+}
+
+fn set_state(state: &mut CircuitState, shared: &Shared, new_state: CircuitState) {
+    *state = new_state;
+    shared.state.store(new_state.to_u8(), Ordering::Release);
+}
+
+impl<St, C> fmt::Debug for CircuitBreaker<St, C>
+where
+    St: fmt::Debug,
+    C: Timer + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CircuitBreaker")
+            .field("stream", &self.stream)
+            .field("config", &self.config)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,64 @@
+use alloc::vec::Vec;
+use core::future::Future;
+use core::mem::take;
+use core::pin::Pin;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::future::FusedFuture;
+use futures_core::stream::FusedStream;
+use futures_core::TryStream;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`collect_partitioned`](crate::TryStreamies::collect_partitioned) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct CollectPartitioned<St>
+    where
+        St: TryStream {
+        #[pin]
+        stream: St,
+        items: Vec<St::Ok>,
+        errors: Vec<St::Error>,
+    }
+}
+
+impl<St: TryStream> CollectPartitioned<St> {
+    pub(crate) fn new(stream: St) -> Self {
+        Self {
+            stream,
+            items: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+}
+
+impl<St> FusedFuture for CollectPartitioned<St>
+where
+    St: TryStream + FusedStream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St> Future for CollectPartitioned<St>
+where
+    St: TryStream,
+{
+    type Output = (Vec<St::Ok>, Vec<St::Error>);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match ready!(this.stream.as_mut().try_poll_next(cx)) {
+                Some(Ok(item)) => this.items.push(item),
+                Some(Err(err)) => this.errors.push(err),
+                None => return Poll::Ready((take(this.items), take(this.errors))),
+            }
+        }
+    }
+}
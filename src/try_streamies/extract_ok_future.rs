@@ -4,9 +4,9 @@ use core::pin::Pin;
 use core::task::Context;
 use core::task::Poll;
 
-use futures::stream::FusedStream;
-use futures::Stream;
-use futures::TryStream;
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
 use pin_project_lite::pin_project;
 
 use crate::futuries::future_result::FutureResult;
@@ -14,7 +14,28 @@ use crate::futuries::ResultExt as _;
 use crate::ready_some;
 
 pin_project! {
-    /// Stream for the [`merge_round_robin`](crate::Streamies::merge_round_robin) method.
+    /// Stream for the [`extract_future_ok`](crate::TryStreamies::extract_future_ok) method.
+    ///
+    /// This adaptor maps items one to one, so it shares the fusing behaviour and the
+    /// size hint of the inner stream. It doesn't fuse the stream by itself.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use core::future::{Ready, ready};
+    /// use futures::stream::{self, FusedStream, Stream, StreamExt};
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let mut stream = stream::iter(vec![Ok::<Ready<i32>, i32>(ready(1)), Err(2)])
+    ///     .fuse()
+    ///     .extract_future_ok();
+    /// assert_eq!(stream.size_hint(), (2, Some(2)));
+    ///
+    /// assert_eq!(stream.next().await.unwrap().await, Ok(1));
+    /// assert_eq!(stream.next().await.unwrap().await, Err(2));
+    /// assert!(stream.next().await.is_none());
+    /// assert!(stream.is_terminated());
+    /// # });
+    /// ```
     #[derive(Debug)]
     #[must_use = "streams do nothing unless polled"]
     pub struct ExtractFutureOk<St> where St: TryStream{
@@ -27,7 +48,7 @@ impl<St> ExtractFutureOk<St>
 where
     St: TryStream,
 {
-    pub(super) fn new(stream: St) -> Self {
+    pub(crate) fn new(stream: St) -> Self {
         Self { stream }
     }
 }
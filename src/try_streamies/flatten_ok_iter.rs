@@ -2,16 +2,38 @@ use core::pin::Pin;
 use core::task::Context;
 use core::task::Poll;
 
-use futures::stream::FusedStream;
-use futures::Stream;
-use futures::TryStream;
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
 use pin_project_lite::pin_project;
 
 use crate::ready_some;
 use crate::ready_some_ok;
 
 pin_project! {
-    /// Stream for the [`flatten_ok_iter`](super::TryStreamExt::flatten_ok_iter) method.
+    /// Stream for the [`flatten_ok_iter`](crate::TryStreamies::flatten_ok_iter) method.
+    ///
+    /// This adaptor doesn't fuse the outer stream by itself. It is terminated once the
+    /// outer stream is terminated and the current iterator has been drained. Polling it
+    /// after it returned `None` polls the outer stream again, so use [`fuse`](https://docs.rs/futures/latest/futures/stream/trait.StreamExt.html#method.fuse)
+    /// if the outer stream doesn't support it.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, FusedStream, Stream, StreamExt};
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let mut stream = stream::iter(vec![Ok::<_, ()>(vec![1, 2])]).fuse().flatten_ok_iter();
+    /// assert_eq!(stream.size_hint(), (0, None));
+    ///
+    /// assert_eq!(stream.next().await, Some(Ok(1)));
+    /// assert_eq!(stream.size_hint(), (1, Some(1)));
+    /// assert_eq!(stream.next().await, Some(Ok(2)));
+    /// assert_eq!(stream.next().await, None);
+    /// assert!(stream.is_terminated());
+    /// assert_eq!(stream.next().await, None);
+    /// # });
+    /// ```
     #[derive(Debug)]
     #[must_use = "streams do nothing unless polled"]
     pub struct FlattenOkIter<St, It>
@@ -31,7 +53,7 @@ where
     St::Ok: IntoIterator<IntoIter = It>,
     It: Iterator,
 {
-    pub(super) fn new(stream: St) -> Self {
+    pub(crate) fn new(stream: St) -> Self {
         Self { stream, iter: None }
     }
 }
@@ -43,7 +65,7 @@ where
     It: Iterator,
 {
     fn is_terminated(&self) -> bool {
-        self.stream.is_terminated()
+        self.iter.is_none() && self.stream.is_terminated()
     }
 }
 
@@ -75,4 +97,18 @@ where
                 .replace(ready_some_ok!(this.stream.as_mut().try_poll_next(cx)).into_iter());
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (iter_lower, iter_upper) = self
+            .iter
+            .as_ref()
+            .map_or((0, Some(0)), |iter| iter.size_hint());
+
+        // Each outer item may be an empty iterator, so they don't count in the lower bound.
+        // The upper bound is only known if no more iterators can come.
+        match self.stream.size_hint() {
+            (_, Some(0)) => (iter_lower, iter_upper),
+            _ => (iter_lower, None),
+        }
+    }
 }
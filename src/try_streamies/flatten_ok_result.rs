@@ -2,15 +2,35 @@ use core::pin::Pin;
 use core::task::Context;
 use core::task::Poll;
 
-use futures::stream::FusedStream;
-use futures::Stream;
-use futures::TryStream;
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
 use pin_project_lite::pin_project;
 
 use crate::ready_some;
 
 pin_project! {
-    /// Stream for the [`try_flatten`](super::TryStreamExt::try_flatten) method.
+    /// Stream for the [`flatten_ok_result`](crate::TryStreamies::flatten_ok_result) method.
+    ///
+    /// This adaptor maps items one to one, so it shares the fusing behaviour and the
+    /// size hint of the inner stream.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, FusedStream, Stream, StreamExt};
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let mut stream = stream::iter(vec![Ok::<Result<i32, i32>, i32>(Ok(1)), Ok(Err(2))])
+    ///     .fuse()
+    ///     .flatten_ok_result();
+    /// assert_eq!(stream.size_hint(), (2, Some(2)));
+    ///
+    /// assert_eq!(stream.next().await, Some(Ok(1)));
+    /// assert_eq!(stream.next().await, Some(Err(2)));
+    /// assert_eq!(stream.next().await, None);
+    /// assert!(stream.is_terminated());
+    /// # });
+    /// ```
     #[derive(Debug)]
     #[must_use = "streams do nothing unless polled"]
     pub struct FlattenOkResult<St>
@@ -19,8 +39,6 @@ pin_project! {
     {
         #[pin]
         stream: St,
-        #[pin]
-        inner_stream: Option<St::Ok>,
     }
 }
 
@@ -28,21 +46,17 @@ impl<St> FlattenOkResult<St>
 where
     St: TryStream,
 {
-    pub(super) fn new(stream: St) -> Self {
-        Self {
-            stream,
-            inner_stream: None,
-        }
+    pub(crate) fn new(stream: St) -> Self {
+        Self { stream }
     }
 }
 
 impl<St, T> FusedStream for FlattenOkResult<St>
 where
     St: TryStream + Stream<Item = Result<Result<T, St::Error>, St::Error>> + FusedStream,
-    St::Ok: Stream,
 {
     fn is_terminated(&self) -> bool {
-        self.inner_stream.is_none() && self.stream.is_terminated()
+        self.stream.is_terminated()
     }
 }
 
@@ -60,4 +74,8 @@ where
             Err(err) | Ok(Err(err)) => Poll::Ready(Some(Err(err))),
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
 }
@@ -0,0 +1,68 @@
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
+use pin_project_lite::pin_project;
+
+use crate::ready_some;
+
+pin_project! {
+    /// Stream for the [`flatten_ok_result_into`](crate::TryStreamies::flatten_ok_result_into) method.
+    ///
+    /// This adaptor maps items one to one, so it shares the fusing behaviour and the
+    /// size hint of the inner stream.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct FlattenOkResultInto<St>
+    where
+        St: TryStream,
+    {
+        #[pin]
+        stream: St,
+    }
+}
+
+impl<St, T, E> FlattenOkResultInto<St>
+where
+    St: TryStream<Ok = Result<T, E>>,
+    St::Error: From<E>,
+{
+    pub(crate) fn new(stream: St) -> Self {
+        Self { stream }
+    }
+}
+
+impl<St, T, E> FusedStream for FlattenOkResultInto<St>
+where
+    St: TryStream<Ok = Result<T, E>> + FusedStream,
+    St::Error: From<E>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, T, E> Stream for FlattenOkResultInto<St>
+where
+    St: TryStream<Ok = Result<T, E>>,
+    St::Error: From<E>,
+{
+    type Item = Result<T, St::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        match ready_some!(this.stream.try_poll_next(cx)) {
+            Ok(Ok(val)) => Poll::Ready(Some(Ok(val))),
+            Ok(Err(err)) => Poll::Ready(Some(Err(err.into()))),
+            Err(err) => Poll::Ready(Some(Err(err))),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
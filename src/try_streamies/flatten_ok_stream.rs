@@ -3,13 +3,35 @@ use core::task::ready;
 use core::task::Context;
 use core::task::Poll;
 
-use futures::stream::FusedStream;
-use futures::Stream;
-use futures::TryStream;
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
 use pin_project_lite::pin_project;
 
 pin_project! {
-    /// Stream for the [`try_flatten`](super::TryStreamExt::try_flatten) method.
+    /// Stream for the [`flatten_ok_stream`](crate::TryStreamies::flatten_ok_stream) method.
+    ///
+    /// The stream is terminated once the outer stream is terminated and the
+    /// last inner stream has been drained.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, FusedStream, Stream, StreamExt};
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let mut stream = stream::iter(vec![Ok::<_, ()>(stream::iter(vec![1, 2]))])
+    ///     .fuse()
+    ///     .flatten_ok_stream();
+    /// assert_eq!(stream.size_hint(), (0, None));
+    ///
+    /// assert_eq!(stream.next().await, Some(Ok(1)));
+    /// assert_eq!(stream.size_hint(), (1, Some(1))); // The outer stream has no more inner streams
+    /// assert_eq!(stream.next().await, Some(Ok(2)));
+    /// assert_eq!(stream.next().await, None);
+    /// assert!(stream.is_terminated());
+    /// assert_eq!(stream.next().await, None);
+    /// # });
+    /// ```
     #[derive(Debug)]
     #[must_use = "streams do nothing unless polled"]
     pub struct FlattenOkStream<St>
@@ -28,7 +50,7 @@ where
     St: TryStream,
     St::Ok: Stream,
 {
-    pub(super) fn new(stream: St) -> Self {
+    pub(crate) fn new(stream: St) -> Self {
         Self {
             stream,
             inner_stream: None,
@@ -69,4 +91,18 @@ where
             }
         })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (inner_lower, inner_upper) = self
+            .inner_stream
+            .as_ref()
+            .map_or((0, Some(0)), |inner| inner.size_hint());
+
+        // Each outer item may be an empty stream, so they don't count in the lower bound.
+        // The upper bound is only known if no more inner streams can come.
+        match self.stream.size_hint() {
+            (_, Some(0)) => (inner_lower, inner_upper),
+            _ => (inner_lower, None),
+        }
+    }
 }
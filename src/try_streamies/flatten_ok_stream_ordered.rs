@@ -0,0 +1,234 @@
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
+use pin_project_lite::pin_project;
+
+use crate::budget::Budget;
+
+/// A position of the outer stream, waiting for its turn to be yielded
+enum Slot<S: Stream, E> {
+    Stream {
+        stream: Pin<Box<S>>,
+        buffer: VecDeque<S::Item>,
+        done: bool,
+    },
+    Err(E),
+}
+
+pin_project! {
+    /// Stream for the [`flatten_ok_stream_ordered`](crate::TryStreamies::flatten_ok_stream_ordered) method.
+    ///
+    /// The inner streams behind the first one are polled concurrently, and their items buffered until it's their turn.
+    /// Each of them buffers at most [`with_buffer_capacity`](Self::with_buffer_capacity) items, 16 by default,
+    /// and isn't polled while its buffer is full.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct FlattenOkStreamOrdered<St>
+    where
+        St: TryStream,
+        St::Ok: Stream,
+    {
+        #[pin]
+        stream: St,
+        slots: VecDeque<Slot<St::Ok, St::Error>>,
+        limit: usize,
+        buffer_capacity: usize,
+        outer_done: bool,
+        budget: Budget,
+    }
+}
+
+impl<St> FlattenOkStreamOrdered<St>
+where
+    St: TryStream,
+    St::Ok: Stream,
+{
+    pub(crate) fn new(stream: St, limit: usize) -> Self {
+        assert!(limit > 0, "the limit must be greater than zero");
+
+        Self {
+            stream,
+            slots: VecDeque::new(),
+            limit,
+            buffer_capacity: 16,
+            outer_done: false,
+            budget: Budget::default(),
+        }
+    }
+
+    /// Set the number of items buffered for each inner stream waiting for its turn.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` is zero.
+    pub fn with_buffer_capacity(mut self, capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "the buffer capacity must be greater than zero"
+        );
+        self.buffer_capacity = capacity;
+        self
+    }
+
+    /// Set the cooperative [`Budget`] of the stream
+    pub fn with_budget(mut self, budget: Budget) -> Self {
+        self.budget = budget;
+        self
+    }
+}
+
+impl<St> FusedStream for FlattenOkStreamOrdered<St>
+where
+    St: TryStream,
+    St::Ok: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.outer_done && self.slots.is_empty()
+    }
+}
+
+impl<St> Stream for FlattenOkStreamOrdered<St>
+where
+    St: TryStream,
+    St::Ok: Stream,
+{
+    type Item = Result<<St::Ok as Stream>::Item, St::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        let mut remaining = this.budget.start();
+
+        loop {
+            // Every position not yielded yet takes a slot, so the memory stays bounded while the head is stalled
+            while !*this.outer_done && this.slots.len() < *this.limit {
+                match this.stream.as_mut().try_poll_next(cx) {
+                    Poll::Ready(Some(Ok(inner))) => this.slots.push_back(Slot::Stream {
+                        stream: Box::pin(inner),
+                        buffer: VecDeque::new(),
+                        done: false,
+                    }),
+                    Poll::Ready(Some(Err(err))) => this.slots.push_back(Slot::Err(err)),
+                    Poll::Ready(None) => *this.outer_done = true,
+                    Poll::Pending => break,
+                }
+            }
+
+            // Let the streams waiting for their turn fill their buffer. They stop once it's full, or once
+            // they returned `Pending` and registered for a wake up
+            for slot in this.slots.iter_mut().skip(1) {
+                if let Slot::Stream {
+                    stream,
+                    buffer,
+                    done: done @ false,
+                } = slot
+                {
+                    while buffer.len() < *this.buffer_capacity {
+                        match stream.as_mut().poll_next(cx) {
+                            Poll::Ready(Some(item)) => buffer.push_back(item),
+                            Poll::Ready(None) => {
+                                *done = true;
+                                break;
+                            }
+                            Poll::Pending => break,
+                        }
+                    }
+                }
+            }
+
+            let Some(head) = this.slots.front_mut() else {
+                return if *this.outer_done {
+                    Poll::Ready(None)
+                } else {
+                    Poll::Pending
+                };
+            };
+
+            let item = match head {
+                Slot::Err(_) => None,
+                Slot::Stream { buffer, .. } if !buffer.is_empty() => buffer.pop_front().map(Ok),
+                Slot::Stream { done: true, .. } => None,
+                Slot::Stream { stream, done, .. } => match stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(item)) => Some(Ok(item)),
+                    Poll::Ready(None) => {
+                        *done = true;
+                        None
+                    }
+                    Poll::Pending => return Poll::Pending,
+                },
+            };
+
+            if let Some(item) = item {
+                return Poll::Ready(Some(item));
+            }
+
+            // The head is an error, or a drained stream. Hand over to the next slot
+            if let Some(Slot::Err(err)) = this.slots.pop_front() {
+                return Poll::Ready(Some(Err(err)));
+            }
+
+            if remaining.tick() {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self
+            .slots
+            .iter()
+            .map(|slot| match slot {
+                Slot::Stream {
+                    stream,
+                    buffer,
+                    done,
+                } => {
+                    let (lower, upper) = if *done {
+                        (0, Some(0))
+                    } else {
+                        stream.size_hint()
+                    };
+                    (
+                        lower.saturating_add(buffer.len()),
+                        upper.and_then(|upper| upper.checked_add(buffer.len())),
+                    )
+                }
+                Slot::Err(_) => (1, Some(1)),
+            })
+            .fold((0usize, Some(0usize)), |(lower, upper), (l, u)| {
+                (
+                    lower.saturating_add(l),
+                    upper.zip(u).and_then(|(upper, u)| upper.checked_add(u)),
+                )
+            });
+
+        // The upper bound is only known if no more inner streams can come
+        if self.outer_done || self.stream.size_hint().1 == Some(0) {
+            (lower, upper)
+        } else {
+            (lower, None)
+        }
+    }
+}
+
+impl<St> core::fmt::Debug for FlattenOkStreamOrdered<St>
+where
+    St: TryStream + core::fmt::Debug,
+    St::Ok: Stream,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FlattenOkStreamOrdered")
+            .field("stream", &self.stream)
+            .field("slots", &self.slots.len())
+            .field("limit", &self.limit)
+            .field("buffer_capacity", &self.buffer_capacity)
+            .field("outer_done", &self.outer_done)
+            .finish_non_exhaustive()
+    }
+}
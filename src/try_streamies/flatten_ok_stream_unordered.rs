@@ -0,0 +1,162 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
+use pin_project_lite::pin_project;
+
+use crate::budget::Budget;
+
+pin_project! {
+    /// Stream for the [`flatten_ok_stream_unordered`](crate::TryStreamies::flatten_ok_stream_unordered) method.
+    ///
+    /// The inner streams are polled in turns, so a busy inner stream can't starve the others.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct FlattenOkStreamUnordered<St>
+    where
+        St: TryStream,
+    {
+        #[pin]
+        stream: St,
+        inner_streams: Vec<Pin<Box<St::Ok>>>,
+        limit: usize,
+        next: usize,
+        outer_done: bool,
+        budget: Budget,
+    }
+}
+
+impl<St> FlattenOkStreamUnordered<St>
+where
+    St: TryStream,
+    St::Ok: Stream,
+{
+    pub(crate) fn new(stream: St, limit: usize) -> Self {
+        assert!(limit > 0, "the limit must be greater than zero");
+
+        Self {
+            stream,
+            inner_streams: Vec::new(),
+            limit,
+            next: 0,
+            outer_done: false,
+            budget: Budget::default(),
+        }
+    }
+
+    /// Set the cooperative [`Budget`] of the stream
+    pub fn with_budget(mut self, budget: Budget) -> Self {
+        self.budget = budget;
+        self
+    }
+}
+
+impl<St> FusedStream for FlattenOkStreamUnordered<St>
+where
+    St: TryStream,
+    St::Ok: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.outer_done && self.inner_streams.is_empty()
+    }
+}
+
+impl<St> Stream for FlattenOkStreamUnordered<St>
+where
+    St: TryStream,
+    St::Ok: Stream,
+{
+    type Item = Result<<St::Ok as Stream>::Item, St::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        let mut remaining = this.budget.start();
+
+        loop {
+            // Start new inner streams while there's room. Errors skip the line
+            while !*this.outer_done && this.inner_streams.len() < *this.limit {
+                match this.stream.as_mut().try_poll_next(cx) {
+                    Poll::Ready(Some(Ok(inner))) => this.inner_streams.push(Box::pin(inner)),
+                    Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err))),
+                    Poll::Ready(None) => *this.outer_done = true,
+                    Poll::Pending => break,
+                }
+            }
+
+            // Poll each inner stream once, starting after the last one that yielded
+            let len = this.inner_streams.len();
+            let mut finished = None;
+            for offset in 0..len {
+                let index = (*this.next + offset) % len;
+
+                match this.inner_streams[index].as_mut().poll_next(cx) {
+                    Poll::Ready(Some(item)) => {
+                        *this.next = index + 1;
+                        return Poll::Ready(Some(Ok(item)));
+                    }
+                    Poll::Ready(None) => {
+                        finished = Some(index);
+                        break;
+                    }
+                    Poll::Pending => {}
+                }
+            }
+
+            match finished {
+                Some(index) => {
+                    // Make room for the next inner stream, and try again
+                    drop(this.inner_streams.swap_remove(index));
+                    *this.next = index;
+                }
+                None if *this.outer_done && this.inner_streams.is_empty() => {
+                    return Poll::Ready(None)
+                }
+                None => return Poll::Pending,
+            }
+
+            if remaining.tick() {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self
+            .inner_streams
+            .iter()
+            .map(|inner| inner.size_hint())
+            .fold((0usize, Some(0usize)), |(lower, upper), (l, u)| {
+                (
+                    lower.saturating_add(l),
+                    upper.zip(u).and_then(|(upper, u)| upper.checked_add(u)),
+                )
+            });
+
+        // The upper bound is only known if no more inner streams can come
+        if self.outer_done || self.stream.size_hint().1 == Some(0) {
+            (lower, upper)
+        } else {
+            (lower, None)
+        }
+    }
+}
+
+impl<St> core::fmt::Debug for FlattenOkStreamUnordered<St>
+where
+    St: TryStream + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FlattenOkStreamUnordered")
+            .field("stream", &self.stream)
+            .field("inner_streams", &self.inner_streams.len())
+            .field("limit", &self.limit)
+            .field("outer_done", &self.outer_done)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,113 @@
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
+use pin_project_lite::pin_project;
+
+use crate::ready_some;
+use crate::ready_some_ok;
+
+pin_project! {
+    /// Stream for the [`flatten_ok_try_iter`](crate::TryStreamies::flatten_ok_try_iter) method.
+    ///
+    /// Like [`FlattenOkIter`](crate::FlattenOkIter), it is terminated once the outer stream is
+    /// terminated and the current iterator has been drained. The size hint is exact while
+    /// the current iterator's is, and no more iterators can come.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, FusedStream, Stream, StreamExt};
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let page = vec![Ok::<_, u8>(1), Err(2)];
+    /// let mut stream = stream::iter(vec![Ok::<_, u16>(page)]).fuse().flatten_ok_try_iter();
+    /// assert_eq!(stream.size_hint(), (0, None));
+    ///
+    /// assert_eq!(stream.next().await, Some(Ok(1)));
+    /// assert_eq!(stream.size_hint(), (1, Some(1)));
+    /// assert_eq!(stream.next().await, Some(Err(2)));
+    /// assert_eq!(stream.next().await, None);
+    /// assert!(stream.is_terminated());
+    /// # });
+    /// ```
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct FlattenOkTryIter<St, It>
+    where
+        St: TryStream,
+        It: Iterator
+    {
+        #[pin]
+        stream: St,
+        iter: Option<It>
+    }
+}
+
+impl<St, It, T, E> FlattenOkTryIter<St, It>
+where
+    St: TryStream,
+    St::Ok: IntoIterator<IntoIter = It>,
+    It: Iterator<Item = Result<T, E>>,
+    St::Error: From<E>,
+{
+    pub(crate) fn new(stream: St) -> Self {
+        Self { stream, iter: None }
+    }
+}
+
+impl<St, It, T, E> FusedStream for FlattenOkTryIter<St, It>
+where
+    St: TryStream + FusedStream,
+    St::Ok: IntoIterator<IntoIter = It>,
+    It: Iterator<Item = Result<T, E>>,
+    St::Error: From<E>,
+{
+    fn is_terminated(&self) -> bool {
+        self.iter.is_none() && self.stream.is_terminated()
+    }
+}
+
+impl<St, It, T, E> Stream for FlattenOkTryIter<St, It>
+where
+    St: TryStream,
+    St::Ok: IntoIterator<IntoIter = It>,
+    It: Iterator<Item = Result<T, E>>,
+    St::Error: From<E>,
+{
+    type Item = Result<T, St::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(iter) = &mut this.iter {
+                if let Some(item) = iter.next() {
+                    return Poll::Ready(Some(item.map_err(From::from)));
+                }
+
+                // The iterator is finished. Drop it before polling the stream
+                this.iter.take();
+            }
+
+            this.iter
+                .replace(ready_some_ok!(this.stream.as_mut().try_poll_next(cx)).into_iter());
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (iter_lower, iter_upper) = self
+            .iter
+            .as_ref()
+            .map_or((0, Some(0)), |iter| iter.size_hint());
+
+        // Each outer item may be an empty iterator, so they don't count in the lower bound.
+        // The upper bound is only known if no more iterators can come.
+        match self.stream.size_hint() {
+            (_, Some(0)) => (iter_lower, iter_upper),
+            _ => (iter_lower, None),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use core::pin::Pin;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`flatten_ok_try_stream`](crate::TryStreamies::flatten_ok_try_stream) method.
+    ///
+    /// The stream is terminated once the outer stream is terminated and the
+    /// last inner stream has been drained. An error of an inner stream doesn't end it.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, FusedStream, Stream, StreamExt};
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let inner = stream::iter(vec![Ok::<_, u8>(1), Err(2)]);
+    /// let mut stream = stream::iter(vec![Ok::<_, u16>(inner)])
+    ///     .fuse()
+    ///     .flatten_ok_try_stream();
+    /// assert_eq!(stream.size_hint(), (0, None));
+    ///
+    /// assert_eq!(stream.next().await, Some(Ok(1)));
+    /// assert_eq!(stream.size_hint(), (1, Some(1))); // The outer stream has no more inner streams
+    /// assert_eq!(stream.next().await, Some(Err(2)));
+    /// assert_eq!(stream.next().await, None);
+    /// assert!(stream.is_terminated());
+    /// # });
+    /// ```
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct FlattenOkTryStream<St>
+    where
+        St: TryStream,
+    {
+        #[pin]
+        stream: St,
+        #[pin]
+        inner_stream: Option<St::Ok>,
+    }
+}
+
+impl<St> FlattenOkTryStream<St>
+where
+    St: TryStream,
+    St::Ok: TryStream,
+    St::Error: From<<St::Ok as TryStream>::Error>,
+{
+    pub(crate) fn new(stream: St) -> Self {
+        Self {
+            stream,
+            inner_stream: None,
+        }
+    }
+}
+
+impl<St> FusedStream for FlattenOkTryStream<St>
+where
+    St: TryStream + FusedStream,
+    St::Ok: TryStream,
+    St::Error: From<<St::Ok as TryStream>::Error>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner_stream.is_none() && self.stream.is_terminated()
+    }
+}
+
+impl<St> Stream for FlattenOkTryStream<St>
+where
+    St: TryStream,
+    St::Ok: TryStream,
+    St::Error: From<<St::Ok as TryStream>::Error>,
+{
+    type Item = Result<<St::Ok as TryStream>::Ok, St::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        Poll::Ready(loop {
+            if let Some(inner_stream) = this.inner_stream.as_mut().as_pin_mut() {
+                match ready!(inner_stream.try_poll_next(cx)) {
+                    Some(item) => break Some(item.map_err(From::from)),
+                    None => this.inner_stream.set(None),
+                }
+            } else if let Some(s) = ready!(this.stream.as_mut().try_poll_next(cx)?) {
+                this.inner_stream.set(Some(s));
+            } else {
+                break None;
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (inner_lower, inner_upper) = self
+            .inner_stream
+            .as_ref()
+            .map_or((0, Some(0)), |inner| inner.size_hint());
+
+        // Each outer item may be an empty stream, so they don't count in the lower bound.
+        // The upper bound is only known if no more inner streams can come.
+        match self.stream.size_hint() {
+            (_, Some(0)) => (inner_lower, inner_upper),
+            _ => (inner_lower, None),
+        }
+    }
+}
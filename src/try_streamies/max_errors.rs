@@ -0,0 +1,129 @@
+use core::fmt;
+use core::pin::Pin;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
+use pin_project_lite::pin_project;
+
+/// Error of the [`MaxErrors`] stream
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MaxErrorsError<E> {
+    /// The stream yielded an error, and the limit isn't reached yet
+    Stream(E),
+
+    /// The stream yielded more errors than the limit. This is the last item of the stream
+    LimitExceeded {
+        /// The maximum number of errors allowed
+        limit: usize,
+        /// The error that got over the limit
+        error: E,
+    },
+}
+
+impl<E: fmt::Display> fmt::Display for MaxErrorsError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stream(err) => err.fmt(f),
+            Self::LimitExceeded { limit, error } => {
+                write!(f, "more than {limit} errors, the last one being: {error}")
+            }
+        }
+    }
+}
+
+impl<E> core::error::Error for MaxErrorsError<E>
+where
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Stream(err) => Some(err),
+            Self::LimitExceeded { error, .. } => Some(error),
+        }
+    }
+}
+
+pin_project! {
+    /// Stream for the [`max_errors`](crate::TryStreamies::max_errors) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct MaxErrors<St> {
+        #[pin]
+        stream: St,
+        limit: usize,
+        seen: usize,
+        done: bool,
+    }
+}
+
+impl<St: TryStream> MaxErrors<St> {
+    pub(crate) fn new(stream: St, limit: usize) -> Self {
+        Self {
+            stream,
+            limit,
+            seen: 0,
+            done: false,
+        }
+    }
+}
+
+impl<St> FusedStream for MaxErrors<St>
+where
+    St: TryStream + FusedStream,
+{
+    fn is_terminated(&self) -> bool {
+        self.done || self.stream.is_terminated()
+    }
+}
+
+impl<St> Stream for MaxErrors<St>
+where
+    St: TryStream,
+{
+    type Item = Result<St::Ok, MaxErrorsError<St::Error>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        let item = match ready!(this.stream.try_poll_next(cx)) {
+            Some(Ok(item)) => Ok(item),
+            Some(Err(error)) => {
+                *this.seen += 1;
+
+                if this.seen > this.limit {
+                    *this.done = true;
+                    Err(MaxErrorsError::LimitExceeded {
+                        limit: *this.limit,
+                        error,
+                    })
+                } else {
+                    Err(MaxErrorsError::Stream(error))
+                }
+            }
+            None => {
+                *this.done = true;
+                return Poll::Ready(None);
+            }
+        };
+
+        Poll::Ready(Some(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        // The stream may get cut off at its next error
+        let (_, upper) = self.stream.size_hint();
+        (0, upper)
+    }
+}
@@ -1,29 +1,106 @@
 use core::hash::Hash;
 
-use futures::Stream;
-use futures::TryStream;
-use futures::TryStreamExt;
+use alloc::string::String;
+use core::future::Future;
+
+use futures_core::Stream;
+use futures_core::TryStream;
+
+use crate::backoff::Backoff;
+#[cfg(feature = "std")]
+use crate::spawner::SpawnError;
+#[cfg(feature = "std")]
+use crate::spawner::Spawner;
+use crate::timer::Clock;
+use crate::timer::Timer;
 
 pub use crate::chunks_ok::ChunksOk;
+pub use crate::circuit_breaker::CircuitBreaker;
+pub use crate::circuit_breaker::CircuitBreakerConfig;
+pub use crate::circuit_breaker::CircuitBreakerError;
+pub use crate::circuit_breaker::CircuitBreakerHandle;
+pub use crate::circuit_breaker::CircuitMetrics;
+pub use crate::circuit_breaker::CircuitOpen;
+pub use crate::circuit_breaker::CircuitState;
+pub use crate::circuit_breaker::OpenBehavior;
+pub use crate::collect_partitioned::CollectPartitioned;
 pub use crate::extract_ok_future::ExtractFutureOk;
-use crate::flatten_ok_iter::FlattenOkIter;
+pub use crate::flatten_ok_iter::FlattenOkIter;
 pub use crate::flatten_ok_result::FlattenOkResult;
+pub use crate::flatten_ok_result_into::FlattenOkResultInto;
 pub use crate::flatten_ok_stream::FlattenOkStream;
+pub use crate::flatten_ok_stream_ordered::FlattenOkStreamOrdered;
+pub use crate::flatten_ok_stream_unordered::FlattenOkStreamUnordered;
+pub use crate::flatten_ok_try_iter::FlattenOkTryIter;
+pub use crate::flatten_ok_try_stream::FlattenOkTryStream;
+pub use crate::max_errors::MaxErrors;
+pub use crate::max_errors::MaxErrorsError;
+#[cfg(feature = "std")]
+pub use crate::par_map_ok_spawned::ParMapOkSpawned;
+pub use crate::reorder_by_seq_ok::ReorderBySeqOk;
+pub use crate::reorder_by_seq_ok::SeqError;
+pub use crate::retry_ok_with::RetryError;
+pub use crate::retry_ok_with::RetryOkWith;
+pub use crate::split_ok_err::SplitErr;
+pub use crate::split_ok_err::SplitOk;
+pub use crate::tap_errors_into::FullSinkPolicy;
+pub use crate::tap_errors_into::SinkErrorPolicy;
+pub use crate::tap_errors_into::TapErrorsInto;
+pub use crate::tap_errors_with::TapErrorsWith;
+pub use crate::then_ok_buffer_unordered::ThenOkBufferUnordered;
+pub use crate::then_ok_buffered::ThenOkBuffered;
+pub use crate::try_collect_all_errors::NonEmptyErrors;
+pub use crate::try_collect_all_errors::TryCollectAllErrors;
+pub use crate::try_collect_btreemap::TryCollectBTreeMap;
+pub use crate::try_collect_hashmap::TryCollectHashMap;
+pub use crate::try_collect_hashset::TryCollectHashSet;
+pub use crate::try_collect_string::TryCollectString;
 pub use crate::try_collect_vec::TryCollectVec;
+pub use crate::try_collect_vec_max::TryCollectLimitError;
+pub use crate::try_collect_vec_max::TryCollectVecMax;
+pub use crate::try_collect_vec_partial::TryCollectVecPartial;
 pub use crate::try_ready_result::ReadyChunksOk;
 pub use crate::unique_by_ok::UniqueByOk;
 pub use crate::unique_ok::UniqueOk;
 
 pub mod chunks_ok;
+pub mod circuit_breaker;
+pub mod collect_partitioned;
 pub mod extract_ok_future;
 pub mod flatten_ok_iter;
 pub mod flatten_ok_result;
+pub mod flatten_ok_result_into;
 pub mod flatten_ok_stream;
+pub mod flatten_ok_stream_ordered;
+pub mod flatten_ok_stream_unordered;
+pub mod flatten_ok_try_iter;
+pub mod flatten_ok_try_stream;
+pub mod max_errors;
+#[cfg(feature = "std")]
+pub mod par_map_ok_spawned;
+pub mod reorder_by_seq_ok;
+pub mod retry_ok_with;
+pub mod split_ok_err;
+pub mod tap_errors_into;
+pub mod tap_errors_with;
+pub mod then_ok_buffer_unordered;
+pub mod then_ok_buffered;
+pub mod try_collect_all_errors;
+pub mod try_collect_btreemap;
+pub mod try_collect_hashmap;
+pub mod try_collect_hashset;
+pub mod try_collect_string;
 pub mod try_collect_vec;
+pub mod try_collect_vec_max;
+pub mod try_collect_vec_partial;
 pub mod try_ready_result;
 pub mod unique_by_ok;
 pub mod unique_ok;
 
+/// Extension trait for [`TryStream`]s.
+///
+/// Like [`Streamies`](crate::Streamies), it is implemented on top of [`futures_core`], so the same adaptors are
+/// available on `futures` and `futures_lite` streams of `Result<T, E>`.
 pub trait TryStreamies: TryStream {
     /// Collect the stream into a vec.
     ///
@@ -46,9 +123,506 @@ pub trait TryStreamies: TryStream {
     /// ```
     fn try_collect_vec(self) -> TryCollectVec<Self>
     where
-        Self: Sized + TryStreamExt,
+        Self: Sized,
+    {
+        TryCollectVec::new(self)
+    }
+
+    /// Collect the stream into a vec, preallocated with the lower bound of the stream's [`size_hint`](Stream::size_hint).
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, String>(1), Ok(2), Ok(3)]);
+    ///
+    /// let result = stream.try_collect_vec_with_capacity().await.unwrap();
+    /// assert_eq!(result, vec![1, 2, 3]);
+    /// assert!(result.capacity() >= 3);
+    /// # });
+    /// ```
+    fn try_collect_vec_with_capacity(self) -> TryCollectVec<Self>
+    where
+        Self: Stream + Sized,
+    {
+        let (lower, _) = self.size_hint();
+        TryCollectVec::with_capacity(self, lower)
+    }
+
+    /// Collect the stream into a vec, stopping at the first error but keeping the values collected before it.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Ok(2), Err("uh oh"), Ok(4)]);
+    /// assert_eq!(stream.try_collect_vec_partial().await, (vec![1, 2], Some("uh oh")));
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, String>(1), Ok(2)]);
+    /// assert_eq!(stream.try_collect_vec_partial().await, (vec![1, 2], None));
+    /// # });
+    /// ```
+    fn try_collect_vec_partial(self) -> TryCollectVecPartial<Self>
+    where
+        Self: Sized,
+    {
+        TryCollectVecPartial::new(self)
+    }
+
+    /// Drain the whole stream, collecting the `Ok` values and the errors into two separate vecs.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Err("uh oh"), Ok(3), Err("oh no")]);
+    /// assert_eq!(stream.collect_partitioned().await, (vec![1, 3], vec!["uh oh", "oh no"]));
+    /// # });
+    /// ```
+    fn collect_partitioned(self) -> CollectPartitioned<Self>
+    where
+        Self: Sized,
+    {
+        CollectPartitioned::new(self)
+    }
+
+    /// Collect the stream into a vec, or return every error of the stream if there's any.
+    ///
+    /// Unlike [`try_collect_vec`](TryStreamies::try_collect_vec), the stream is drained even after an error.
+    /// The `Ok` values are dropped as soon as the first error is received.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, String>(1), Ok(2)]);
+    /// assert_eq!(stream.try_collect_all_errors().await, Ok(vec![1, 2]));
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Err("uh oh"), Ok(3), Err("oh no")]);
+    /// let errors = stream.try_collect_all_errors().await.unwrap_err();
+    /// assert_eq!(errors.first(), &"uh oh");
+    /// assert_eq!(errors.into_vec(), vec!["uh oh", "oh no"]);
+    /// # });
+    /// ```
+    fn try_collect_all_errors(self) -> TryCollectAllErrors<Self>
+    where
+        Self: Sized,
+    {
+        TryCollectAllErrors::new(self)
+    }
+
+    /// Pass the items through, but end the stream once more than `limit` errors have been yielded.
+    ///
+    /// The errors up to the limit are yielded as [`MaxErrorsError::Stream`]. The one getting over it is
+    /// yielded as [`MaxErrorsError::LimitExceeded`], and the stream ends right after.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use streamies::{MaxErrorsError, Streamies as _, TryStreamies as _};
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Err("a"), Ok(2), Err("b"), Ok(3), Err("c"), Ok(4)]);
+    ///
+    /// let result = stream.max_errors(1).collect_vec().await;
+    /// assert_eq!(
+    ///     result,
+    ///     vec![
+    ///         Ok(1),
+    ///         Err(MaxErrorsError::Stream("a")),
+    ///         Ok(2),
+    ///         Err(MaxErrorsError::LimitExceeded { limit: 1, error: "b" }),
+    ///     ]
+    /// );
+    /// # });
+    /// ```
+    fn max_errors(self, limit: usize) -> MaxErrors<Self>
+    where
+        Self: Sized,
+    {
+        MaxErrors::new(self, limit)
+    }
+
+    /// Split the stream into a stream of `Ok` values and a stream of errors, that can be consumed concurrently.
+    ///
+    /// Whichever half is polled drives the inner stream, and buffers the items meant for the other half.
+    /// Each buffer holds at most `capacity` items: once it is full, the half polling the inner stream
+    /// waits for the other one to catch up. A slow error consumer thus pauses the ok side, and the other way around.
+    ///
+    /// If one of the halves is dropped, its items are discarded, and the other half is never paused.
+    ///
+    /// The halves share their state behind a lock, that is never held while polling the inner stream.
+    /// Without the `std` feature, this lock is a spin lock.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` is zero.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Err("uh oh"), Ok(2), Err("oh no"), Ok(3)]);
+    /// let (oks, errs) = stream.split_ok_err(1);
+    ///
+    /// let (oks, errs) = futures::join!(oks.collect::<Vec<_>>(), errs.collect::<Vec<_>>());
+    /// assert_eq!(oks, vec![1, 2, 3]);
+    /// assert_eq!(errs, vec!["uh oh", "oh no"]);
+    /// # });
+    /// ```
+    fn split_ok_err(self, capacity: usize) -> (SplitOk<Self>, SplitErr<Self>)
+    where
+        Self: Sized,
+    {
+        split_ok_err::split_ok_err(self, capacity)
+    }
+
+    /// Send every error to a dead-letter sink, and only yield the `Ok` values.
+    ///
+    /// By default, the stream waits for the sink to be ready before going on, and stops sending
+    /// to the sink once it failed. Both can be changed with [`on_full`](TapErrorsInto::on_full)
+    /// and [`on_sink_error`](TapErrorsInto::on_sink_error).
+    ///
+    /// With the default policy, the stream ends once the sink is flushed. The sink is never closed.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::stream::{self, StreamExt};
+    /// use streamies::{FullSinkPolicy, Streamies as _, TryStreamies as _};
+    ///
+    /// let (tx, rx) = mpsc::unbounded();
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Err("uh oh"), Ok(2), Err("oh no")]);
+    /// assert_eq!(stream.tap_errors_into(tx).collect_vec().await, vec![1, 2]);
+    /// assert_eq!(rx.collect_vec().await, vec!["uh oh", "oh no"]);
+    ///
+    /// // A bounded channel must be drained concurrently, as the stream waits for it
+    /// let (tx, rx) = mpsc::channel(0);
+    ///
+    /// let stream = stream::iter(vec![Err("uh oh"), Err("oh no"), Ok(1)]);
+    /// let (values, errors) = futures::join!(stream.tap_errors_into(tx).collect_vec(), rx.collect_vec());
+    /// assert_eq!(values, vec![1]);
+    /// assert_eq!(errors, vec!["uh oh", "oh no"]);
+    ///
+    /// // Drop the errors that don't fit in the channel instead of waiting
+    /// let (tx, rx) = mpsc::channel(0);
+    ///
+    /// let stream = stream::iter(vec![Err("uh oh"), Err("oh no"), Ok(1)]);
+    /// let stream = stream.tap_errors_into(tx).on_full(FullSinkPolicy::DropError);
+    /// assert_eq!(stream.collect_vec().await, vec![1]);
+    /// assert_eq!(rx.collect_vec().await, vec!["uh oh"]);
+    /// # });
+    /// ```
+    fn tap_errors_into<Si>(self, sink: Si) -> TapErrorsInto<Self, Si>
+    where
+        Self: Sized,
+        Si: futures_sink::Sink<Self::Error>,
+    {
+        TapErrorsInto::new(self, sink)
+    }
+
+    /// Call `f` on every error, and only yield the `Ok` values.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use streamies::{Streamies as _, TryStreamies as _};
+    ///
+    /// let mut errors = Vec::new();
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Err("uh oh"), Ok(2), Err("oh no")]);
+    /// let values = stream.tap_errors_with(|err| errors.push(err)).collect_vec().await;
+    ///
+    /// assert_eq!(values, vec![1, 2]);
+    /// assert_eq!(errors, vec!["uh oh", "oh no"]);
+    /// # });
+    /// ```
+    fn tap_errors_with<F>(self, f: F) -> TapErrorsWith<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Error),
+    {
+        TapErrorsWith::new(self, f)
+    }
+
+    /// Run the async operation `op` on each `Ok` value, retrying it up to `max_attempts` times in total.
+    ///
+    /// Between two attempts, the stream waits for the delay of the [`Backoff`] policy.
+    /// Once every attempt failed, the last error is yielded as [`RetryError::Exhausted`], with the number of attempts.
+    /// The errors of the inner stream aren't retried, and are yielded as [`RetryError::Upstream`].
+    /// The error type of `op` is independent from the stream's one.
+    ///
+    /// The items are processed one at a time, in order.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `max_attempts` is zero.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use core::cell::Cell;
+    /// use core::time::Duration;
+    /// use futures::stream;
+    /// use streamies::{Backoff, RetryError, Streamies as _, TryStreamies as _};
+    ///
+    /// let calls = Cell::new(0);
+    /// let flaky_double = |i: &i32| {
+    ///     calls.set(calls.get() + 1);
+    ///     let result = if calls.get() % 3 == 0 { Ok(i * 2) } else { Err(503) };
+    ///     async move { result }
+    /// };
+    ///
+    /// // Don't actually wait in this example
+    /// let backoff = Backoff::new(|_: Duration| async {});
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Err("upstream")]);
+    /// let results = stream.retry_ok_with(3, backoff.clone(), flaky_double).collect_vec().await;
+    /// assert_eq!(results, vec![Ok(2), Err(RetryError::Upstream("upstream"))]);
+    ///
+    /// let results = stream::iter(vec![Ok::<_, &str>(1)]).retry_ok_with(2, backoff, flaky_double).collect_vec().await;
+    /// assert_eq!(results, vec![Err(RetryError::Exhausted { attempts: 2, error: 503 })]);
+    /// # });
+    /// ```
+    fn retry_ok_with<T, F, Fut, O, OpE>(
+        self,
+        max_attempts: u32,
+        backoff: Backoff<T>,
+        op: F,
+    ) -> RetryOkWith<Self, T, F, Fut>
+    where
+        Self: Sized,
+        T: Timer,
+        F: FnMut(&Self::Ok) -> Fut,
+        Fut: Future<Output = Result<O, OpE>>,
+    {
+        RetryOkWith::new(self, max_attempts, backoff, op)
+    }
+
+    /// Track the error rate of the items, and open the circuit when it gets too high.
+    ///
+    /// While the circuit is open, the items are either replaced by [`CircuitBreakerError::Open`], or the
+    /// inner stream isn't polled at all, depending on the [`OpenBehavior`]. Once the cooldown measured by the
+    /// [`Clock`] is over, the circuit half-opens: a success closes it, while an error opens it again.
+    ///
+    /// The state and counters of the circuit can be followed through [`CircuitBreaker::handle`].
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use core::time::Duration;
+    /// use futures::stream::{self, StreamExt};
+    /// use streamies::timer::ManualClock;
+    /// use streamies::{CircuitBreakerConfig, CircuitBreakerError, CircuitState, TryStreamies as _};
+    ///
+    /// let clock = ManualClock::new();
+    /// let config = CircuitBreakerConfig::new(clock.clone()).window(2).cooldown(Duration::from_secs(10));
+    ///
+    /// let stream = stream::iter(vec![Err("down"), Err("down"), Ok(1), Ok(2), Ok(3)]);
+    /// let mut stream = stream.circuit_breaker(config);
+    /// let handle = stream.handle();
+    ///
+    /// assert_eq!(stream.next().await, Some(Err(CircuitBreakerError::Upstream("down"))));
+    /// assert_eq!(stream.next().await, Some(Err(CircuitBreakerError::Upstream("down"))));
+    /// assert_eq!(handle.state(), CircuitState::Open);
+    /// assert!(matches!(stream.next().await, Some(Err(CircuitBreakerError::Open(_)))));
+    ///
+    /// clock.advance(Duration::from_secs(10));
+    /// assert_eq!(stream.next().await, Some(Ok(2)));
+    /// assert_eq!(handle.state(), CircuitState::Closed);
+    /// assert_eq!(handle.metrics().rejected, 1);
+    /// # });
+    /// ```
+    ///
+    /// With [`OpenBehavior::Pause`], nothing is rejected, the stream waits for the cooldown instead:
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use core::task::Poll;
+    /// use core::time::Duration;
+    /// use futures::stream::{self, StreamExt};
+    /// use streamies::timer::ManualClock;
+    /// use streamies::{CircuitBreakerConfig, OpenBehavior, TryStreamies as _};
+    ///
+    /// let clock = ManualClock::new();
+    /// let config = CircuitBreakerConfig::new(clock.clone())
+    ///     .window(2)
+    ///     .cooldown(Duration::from_secs(10))
+    ///     .on_open(OpenBehavior::Pause);
+    ///
+    /// let stream = stream::iter(vec![Err("down"), Err("down"), Ok(1)]);
+    /// let mut stream = stream.circuit_breaker(config);
+    ///
+    /// stream.next().await;
+    /// stream.next().await;
+    /// assert_eq!(futures::poll!(stream.next()), Poll::Pending);
+    ///
+    /// clock.advance(Duration::from_secs(10));
+    /// assert_eq!(stream.next().await, Some(Ok(1)));
+    /// # });
+    /// ```
+    fn circuit_breaker<C>(self, config: CircuitBreakerConfig<C>) -> CircuitBreaker<Self, C>
+    where
+        Self: Sized,
+        C: Clock,
+    {
+        CircuitBreaker::new(self, config)
+    }
+
+    /// Collect the stream into a vec, failing if the stream yields more than `max` `Ok` values.
+    ///
+    /// This protects against unbounded streams filling up the memory. Once the limit is exceeded,
+    /// the stream isn't polled anymore, and the items received so far are returned in the
+    /// [`TryCollectLimitError::LimitExceeded`] error. `Err` values are returned as [`TryCollectLimitError::Stream`].
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use streamies::{TryCollectLimitError, TryStreamies as _};
+    ///
+    /// let result = stream::iter(vec![Ok::<i32, String>(1), Ok(2)]).try_collect_vec_max(3).await;
+    /// assert_eq!(result, Ok(vec![1, 2]));
+    ///
+    /// let result = stream::repeat(Ok::<i32, String>(1)).try_collect_vec_max(3).await;
+    /// let Err(TryCollectLimitError::LimitExceeded(err)) = result else { panic!() };
+    /// assert_eq!(err.into_items(), vec![1, 1, 1, 1]);
+    ///
+    /// let result = stream::iter(vec![Ok(1), Err("uh oh")]).try_collect_vec_max(3).await;
+    /// assert_eq!(result, Err(TryCollectLimitError::Stream("uh oh")));
+    /// # });
+    /// ```
+    fn try_collect_vec_max(self, max: usize) -> TryCollectVecMax<Self, fn(&Self::Ok) -> usize>
+    where
+        Self: Sized,
+    {
+        TryCollectVecMax::new(self, max, |_| 1)
+    }
+
+    /// Collect the stream into a vec, failing if the total weight of the `Ok` values is more than `max_weight`.
+    ///
+    /// The weight of each item is computed by `weight_fn`, which allows limiting the collection by bytes instead of item count.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use streamies::{TryCollectLimitError, TryStreamies as _};
+    ///
+    /// let pages = vec![Ok::<_, String>(vec![0u8; 4]), Ok(vec![0u8; 4]), Ok(vec![0u8; 4])];
+    ///
+    /// let result = stream::iter(pages).try_collect_vec_max_weight(10, |page| page.len()).await;
+    /// let Err(TryCollectLimitError::LimitExceeded(err)) = result else { panic!() };
+    /// assert_eq!(err.limit(), 10);
+    /// assert_eq!(err.items().len(), 3);
+    /// # });
+    /// ```
+    fn try_collect_vec_max_weight<F>(
+        self,
+        max_weight: usize,
+        weight_fn: F,
+    ) -> TryCollectVecMax<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Ok) -> usize,
+    {
+        TryCollectVecMax::new(self, max_weight, weight_fn)
+    }
+
+    /// Collect a stream of key-value pairs into a hashmap. Later values overwrite the earlier ones with the same key.
+    ///
+    /// The first `Err` value is returned instead, and the stream isn't polled anymore.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let stream = stream::iter(vec![Ok::<_, String>(("a", 1)), Ok(("b", 2)), Ok(("a", 3))]);
+    ///
+    /// let result = stream.try_collect_hashmap().await.unwrap();
+    /// assert_eq!(result.len(), 2);
+    /// assert_eq!(result["a"], 3);
+    ///
+    /// let stream = stream::iter(vec![Ok(("a", 1)), Err("uh oh")]);
+    /// assert_eq!(stream.try_collect_hashmap().await, Err("uh oh"));
+    /// # });
+    /// ```
+    fn try_collect_hashmap<K, V>(self) -> TryCollectHashMap<Self, K, V>
+    where
+        Self: TryStream<Ok = (K, V)> + Sized,
+        K: Eq + Hash,
+    {
+        TryCollectHashMap::new(self)
+    }
+
+    /// Collect the stream into a hashset.
+    ///
+    /// The first `Err` value is returned instead, and the stream isn't polled anymore.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let stream = stream::iter(vec![Ok::<_, String>(1), Ok(2), Ok(1)]);
+    ///
+    /// let result = stream.try_collect_hashset().await.unwrap();
+    /// assert_eq!(result.len(), 2);
+    /// # });
+    /// ```
+    fn try_collect_hashset(self) -> TryCollectHashSet<Self>
+    where
+        Self: Sized,
+        Self::Ok: Eq + Hash,
+    {
+        TryCollectHashSet::new(self)
+    }
+
+    /// Collect a stream of key-value pairs into a btreemap. Later values overwrite the earlier ones with the same key.
+    ///
+    /// The first `Err` value is returned instead, and the stream isn't polled anymore.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use std::collections::BTreeMap;
+    /// use futures::stream;
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let stream = stream::iter(vec![Ok::<_, String>(("b", 2)), Ok(("a", 1))]);
+    ///
+    /// let result = stream.try_collect_btreemap().await;
+    /// assert_eq!(result, Ok(BTreeMap::from([("a", 1), ("b", 2)])));
+    /// # });
+    /// ```
+    fn try_collect_btreemap<K, V>(self) -> TryCollectBTreeMap<Self, K, V>
+    where
+        Self: TryStream<Ok = (K, V)> + Sized,
+        K: Ord,
     {
-        TryCollectVec::new(self.try_collect())
+        TryCollectBTreeMap::new(self)
+    }
+
+    /// Collect the stream into a string. Any item that can extend a `String` is accepted (`char`, `&str`, `String`...)
+    ///
+    /// The first `Err` value is returned instead, and the stream isn't polled anymore.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let stream = stream::iter(vec![Ok::<_, String>("Hello"), Ok(" "), Ok("there!")]);
+    /// assert_eq!(stream.try_collect_string().await, Ok("Hello there!".to_string()));
+    /// # });
+    /// ```
+    fn try_collect_string(self) -> TryCollectString<Self>
+    where
+        Self: Sized,
+        String: Extend<Self::Ok>,
+    {
+        TryCollectString::new(self)
     }
 
     /// An adaptor for chunking up successful, ready items of the stream inside a vector.
@@ -66,7 +640,7 @@ pub trait TryStreamies: TryStream {
     /// yielded.
     ///
     /// This function is similar to
-    /// [`TryReadyChunksError::try_ready_chunks`](futures::stream::TryStreamExt::try_ready_chunks) but
+    /// [`TryReadyChunksError::try_ready_chunks`](https://docs.rs/futures/latest/futures/stream/trait.TryStreamExt.html#method.try_ready_chunks) but
     /// with a key distinction. The stream doesn't return any "error" value containing the
     /// current chunk and the error. Instead it yield the current chunk, and the error will get
     /// yielded on the next poll
@@ -91,12 +665,26 @@ pub trait TryStreamies: TryStream {
     /// # })
     /// ```
     ///
+    /// The cooperative budget only yields back to the executor, so ready items are never split into smaller chunks:
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, TryStreamExt};
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let mut stream = stream::iter((0..100).map(Ok::<i32, ()>)).ready_chunks_ok(100);
+    ///
+    /// assert_eq!(stream.try_next().await, Ok(Some((0..100).collect())));
+    /// assert_eq!(stream.try_next().await, Ok(None));
+    /// # })
+    /// ```
+    ///
     /// # Panics
     ///
     /// This method will panic if `capacity` is zero.
     fn ready_chunks_ok(self, cap: usize) -> ReadyChunksOk<Self>
     where
-        Self: Sized + TryStreamExt,
+        Self: Sized,
     {
         ReadyChunksOk::new(self, cap)
     }
@@ -121,11 +709,211 @@ pub trait TryStreamies: TryStream {
     /// ```
     fn extract_future_ok(self) -> ExtractFutureOk<Self>
     where
-        Self: Sized + TryStreamExt,
+        Self: Sized,
     {
         ExtractFutureOk::new(self)
     }
 
+    /// Run an async operation on the `Ok` values, with at most `limit` of them in flight, yielding their outputs in order.
+    ///
+    /// The error of the future is flattened into the stream. `Err` values of the stream are kept at their position,
+    /// but only the running futures count toward `limit`: the errors, and the outputs waiting for an earlier
+    /// future to finish, are buffered on top of it.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `limit` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use streamies::{Streamies as _, TryStreamies as _};
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Err("no id"), Ok(2), Ok(3)]);
+    /// let results = stream
+    ///     .then_ok_buffered(2, |id| async move {
+    ///         if id == 3 { Err("not found") } else { Ok(id * 10) }
+    ///     })
+    ///     .collect_vec()
+    ///     .await;
+    ///
+    /// assert_eq!(results, vec![Ok(10), Err("no id"), Ok(20), Err("not found")]);
+    /// # })
+    /// ```
+    fn then_ok_buffered<F, Fut, T>(self, limit: usize, f: F) -> ThenOkBuffered<Self, F, Fut>
+    where
+        Self: Sized,
+        F: FnMut(Self::Ok) -> Fut,
+        Fut: Future<Output = Result<T, Self::Error>>,
+    {
+        ThenOkBuffered::new(self, limit, f)
+    }
+
+    /// Run an async operation on the `Ok` values, with at most `limit` of them in flight, yielding their outputs as soon as they complete.
+    ///
+    /// The error of the future is flattened into the stream. `Err` values of the stream are yielded as soon as they are received.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `limit` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use core::task::Poll;
+    /// use futures::{future, stream};
+    /// use streamies::{Streamies as _, TryStreamies as _};
+    ///
+    /// // Stays pending for one poll
+    /// async fn yield_now() {
+    ///     let mut yielded = false;
+    ///     future::poll_fn(|cx| {
+    ///         if yielded {
+    ///             return Poll::Ready(());
+    ///         }
+    ///         yielded = true;
+    ///         cx.waker().wake_by_ref();
+    ///         Poll::Pending
+    ///     })
+    ///     .await
+    /// }
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Ok(2), Err("no id")]);
+    /// let results = stream
+    ///     .then_ok_buffer_unordered(2, |id| async move {
+    ///         if id == 1 {
+    ///             yield_now().await;
+    ///         }
+    ///         Ok(id * 10)
+    ///     })
+    ///     .collect_vec()
+    ///     .await;
+    ///
+    /// assert_eq!(results, vec![Ok(20), Err("no id"), Ok(10)]);
+    /// # })
+    /// ```
+    fn then_ok_buffer_unordered<F, Fut, T>(
+        self,
+        limit: usize,
+        f: F,
+    ) -> ThenOkBufferUnordered<Self, F, Fut>
+    where
+        Self: Sized,
+        F: FnMut(Self::Ok) -> Fut,
+        Fut: Future<Output = Result<T, Self::Error>>,
+    {
+        ThenOkBufferUnordered::new(self, limit, f)
+    }
+
+    /// Run an async function on the `Ok` values in tasks spawned on `spawner`, with up to `limit` tasks running at once,
+    /// and yield their outputs in order.
+    ///
+    /// This works like [`par_map_spawned`](crate::Streamies::par_map_spawned). The `Err` values are kept at their position,
+    /// and a task that panics yields the [`SpawnError`] converted into the error of the stream.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `limit` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "tokio")]
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use futures::stream;
+    /// use streamies::spawner::{SpawnError, TokioSpawner};
+    /// use streamies::{Streamies as _, TryStreamies as _};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Error {
+    ///     Parse,
+    ///     Worker(SpawnError),
+    /// }
+    ///
+    /// impl From<SpawnError> for Error {
+    ///     fn from(err: SpawnError) -> Self {
+    ///         Error::Worker(err)
+    ///     }
+    /// }
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Err(Error::Parse), Ok(2)]);
+    /// let results = stream
+    ///     .par_map_ok_spawned(2, TokioSpawner, |n| async move { n * 10 })
+    ///     .collect_vec()
+    ///     .await;
+    ///
+    /// assert_eq!(results, vec![Ok(10), Err(Error::Parse), Ok(20)]);
+    /// # }
+    /// # #[cfg(not(feature = "tokio"))]
+    /// # fn main() {}
+    /// ```
+    #[cfg(feature = "std")]
+    fn par_map_ok_spawned<S, F, Fut>(
+        self,
+        limit: usize,
+        spawner: S,
+        f: F,
+    ) -> ParMapOkSpawned<Self, S, F, Fut>
+    where
+        Self: Sized,
+        Self::Error: From<SpawnError>,
+        S: Spawner,
+        F: FnMut(Self::Ok) -> Fut,
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static,
+    {
+        ParMapOkSpawned::new(self, limit, spawner, f)
+    }
+
+    /// Restore the order of the `Ok` values carrying a sequence number, given by `seq_fn`.
+    ///
+    /// This works like [`reorder_by_seq`](crate::Streamies::reorder_by_seq), with the skipped sequence numbers
+    /// reported as [`SeqError::Gap`] errors. Duplicated values are dropped, and reported as [`SeqError::Duplicate`].
+    /// Errors of the stream pass through as soon as they are received.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use streamies::{SeqError, Streamies as _, TryStreamies as _};
+    ///
+    /// let messages = vec![Ok(1), Ok(2), Ok(5), Err("disconnected"), Ok(4), Ok(6), Ok(7)];
+    /// let results = stream::iter(messages)
+    ///     .reorder_by_seq_ok(|seq| *seq, 2, 10)
+    ///     .with_start(1)
+    ///     .collect_vec()
+    ///     .await;
+    ///
+    /// assert_eq!(results, vec![
+    ///     Ok(1),
+    ///     Ok(2),
+    ///     Err(SeqError::Upstream("disconnected")),
+    ///     Err(SeqError::Gap { missing: 3..4 }), // 6 is too far ahead of 3
+    ///     Ok(4),
+    ///     Ok(5),
+    ///     Ok(6),
+    ///     Ok(7),
+    /// ]);
+    /// # })
+    /// ```
+    fn reorder_by_seq_ok<F>(
+        self,
+        seq_fn: F,
+        max_gap: u64,
+        max_buffer: usize,
+    ) -> ReorderBySeqOk<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Ok) -> u64,
+    {
+        ReorderBySeqOk::new(self, seq_fn, max_gap, max_buffer)
+    }
+
     /// Flatten the result from the `Ok` value into the stream
     ///
     /// # Example
@@ -151,6 +939,35 @@ pub trait TryStreamies: TryStream {
         FlattenOkResult::new(self)
     }
 
+    /// Flatten the result from the `Ok` value into the stream, converting its error into the stream's error.
+    ///
+    /// Unlike [`flatten_ok_result`](TryStreamies::flatten_ok_result), the inner error type only needs to implement
+    /// `Into` the error of the stream.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let stream = stream::iter(vec![Ok::<Result<i32, u8>, u16>(Ok(1)), Ok(Err(2)), Err(3)]);
+    /// let mut stream = stream.flatten_ok_result_into();
+    ///
+    /// assert_eq!(stream.next().await, Some(Ok(1)));
+    /// assert_eq!(stream.next().await, Some(Err(2u16)));
+    /// assert_eq!(stream.next().await, Some(Err(3)));
+    /// assert_eq!(stream.next().await, None);
+    /// # })
+    /// ```
+    fn flatten_ok_result_into<T, E>(self) -> FlattenOkResultInto<Self>
+    where
+        Self: TryStream<Ok = Result<T, E>> + Sized,
+        Self::Error: From<E>,
+    {
+        FlattenOkResultInto::new(self)
+    }
+
     /// Flatten a stream of `Result<impl IntoIterator<T>, E>` into `Result<T, E>`. Items are yielded in the order of the Iterator
     ///
     /// # Examples
@@ -184,12 +1001,46 @@ pub trait TryStreamies: TryStream {
         FlattenOkIter::new(self)
     }
 
+    /// Flatten a stream of `Result<impl IntoIterator<Result<T, E2>>, E>` into `Result<T, E>`, converting the errors of the
+    /// iterators with `From`. Items are yielded in the order of the Iterator
+    ///
+    /// This is useful for pages parsed into a `Vec<Result<T, E2>>`, which would otherwise need
+    /// [`flatten_ok_iter`](TryStreamies::flatten_ok_iter) then [`flatten_ok_result`](TryStreamies::flatten_ok_result).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let pages = vec![Ok(vec![Ok(1), Err("bad item"), Ok(2)]), Err(String::from("bad page")), Ok(vec![Ok(3)])];
+    /// let mut stream = stream::iter(pages).flatten_ok_try_iter();
+    ///
+    /// assert_eq!(stream.next().await, Some(Ok(1)));
+    /// assert_eq!(stream.next().await, Some(Err(String::from("bad item"))));
+    /// assert_eq!(stream.next().await, Some(Ok(2)));
+    /// assert_eq!(stream.next().await, Some(Err(String::from("bad page"))));
+    /// assert_eq!(stream.next().await, Some(Ok(3)));
+    /// assert_eq!(stream.next().await, None);
+    /// # });
+    /// ```
+    fn flatten_ok_try_iter<It, T, E>(self) -> FlattenOkTryIter<Self, It>
+    where
+        Self: Sized,
+        Self::Ok: IntoIterator<IntoIter = It>,
+        It: Iterator<Item = Result<T, E>>,
+        Self::Error: From<E>,
+    {
+        FlattenOkTryIter::new(self)
+    }
+
     /// Flattens a stream of streams into just one continuous stream.
     ///
     /// Values yielded by the inner streams will get assigned to `Ok` values,
     /// while `Err` values will pass through
     ///
-    /// The difference between this combinator and [`try_flatten`](futures::stream::TryStreamExt::try_flatten)
+    /// The difference between this combinator and [`try_flatten`](https://docs.rs/futures/latest/futures/stream/trait.TryStreamExt.html#method.try_flatten)
     /// is that it doesn't flatten `Err` values if the inner stream return
     /// `Result`s
     ///
@@ -223,6 +1074,126 @@ pub trait TryStreamies: TryStream {
         FlattenOkStream::new(self)
     }
 
+    /// Flattens a stream of streams, polling up to `limit` inner streams concurrently.
+    ///
+    /// Like [`flatten_ok_stream`](TryStreamies::flatten_ok_stream), values yielded by the inner streams will get
+    /// assigned to `Ok` values, while `Err` values will pass through as soon as they are received.
+    ///
+    /// The items are yielded in the order they are ready, so the items of different inner streams get interleaved.
+    /// Use [`flatten_ok_stream_ordered`](TryStreamies::flatten_ok_stream_ordered) to keep the order of the outer stream.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `limit` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use streamies::{Streamies as _, TryStreamies as _};
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let pending_page = stream::pending::<i32>();
+    /// let other_page = stream::iter(vec![5, 6]);
+    /// let stream = stream::iter(vec![Ok(pending_page.boxed()), Err(4), Ok(other_page.boxed())])
+    ///     .flatten_ok_stream_unordered(2);
+    ///
+    /// // The pending inner stream doesn't block the others
+    /// let items = stream.take(3).collect_vec().await;
+    /// assert_eq!(items, vec![Err(4), Ok(5), Ok(6)]);
+    /// # });
+    /// ```
+    fn flatten_ok_stream_unordered(self, limit: usize) -> FlattenOkStreamUnordered<Self>
+    where
+        Self::Ok: Stream,
+        Self: Sized,
+    {
+        FlattenOkStreamUnordered::new(self, limit)
+    }
+
+    /// Flattens a stream of streams, polling up to `limit` inner streams concurrently, while keeping their order.
+    ///
+    /// The first inner stream is yielded directly, while the items of the next ones are buffered until their turn comes.
+    /// `Err` values keep their position in the outer stream.
+    ///
+    /// At most `limit` positions of the outer stream are held at once, errors included. The buffer of each waiting
+    /// inner stream is bounded too, see [`FlattenOkStreamOrdered::with_buffer_capacity`].
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `limit` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use streamies::{Streamies as _, TryStreamies as _};
+    /// use futures::stream;
+    ///
+    /// let first = stream::iter(vec![1, 2, 3]);
+    /// let second = stream::iter(vec![5, 6]);
+    /// let stream = stream::iter(vec![Ok(first), Err(4), Ok(second)])
+    ///     .flatten_ok_stream_ordered(2);
+    ///
+    /// let items = stream.collect_vec().await;
+    /// assert_eq!(items, vec![Ok(1), Ok(2), Ok(3), Err(4), Ok(5), Ok(6)]);
+    /// # });
+    /// ```
+    fn flatten_ok_stream_ordered(self, limit: usize) -> FlattenOkStreamOrdered<Self>
+    where
+        Self::Ok: Stream,
+        Self: Sized,
+    {
+        FlattenOkStreamOrdered::new(self, limit)
+    }
+
+    /// Flattens a stream of fallible streams into just one continuous stream.
+    ///
+    /// Values yielded by the inner streams are passed through, with their errors converted into the error of the
+    /// outer stream. This avoids a `Result<Result<T, InnerErr>, E>` needing a [`flatten_ok_result`](TryStreamies::flatten_ok_result)
+    /// with matching error types.
+    ///
+    /// An error from an inner stream doesn't stop it. Its next items are still yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use streamies::{Streamies as _, TryStreamies as _};
+    /// use futures::stream;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Error {
+    ///     Listing,
+    ///     Page(&'static str),
+    /// }
+    ///
+    /// impl From<&'static str> for Error {
+    ///     fn from(err: &'static str) -> Self {
+    ///         Error::Page(err)
+    ///     }
+    /// }
+    ///
+    /// let first = stream::iter(vec![Ok(1), Err("bad item"), Ok(2)]);
+    /// let second = stream::iter(vec![Ok(3)]);
+    /// let stream = stream::iter(vec![Ok(first), Err(Error::Listing), Ok(second)])
+    ///     .flatten_ok_try_stream();
+    ///
+    /// assert_eq!(
+    ///     stream.collect_vec().await,
+    ///     vec![Ok(1), Err(Error::Page("bad item")), Ok(2), Err(Error::Listing), Ok(3)]
+    /// );
+    /// # });
+    /// ```
+    fn flatten_ok_try_stream(self) -> FlattenOkTryStream<Self>
+    where
+        Self::Ok: TryStream,
+        Self::Error: From<<Self::Ok as TryStream>::Error>,
+        Self: Sized,
+    {
+        FlattenOkTryStream::new(self)
+    }
+
     /// An adaptor for chunking up items of the stream inside a vector.
     ///
     /// This combinator will attempt to pull `Ok` items from this stream and buffer
@@ -266,7 +1237,7 @@ pub trait TryStreamies: TryStream {
     /// This method will panic if `capacity` is zero.
     fn chunks_ok(self, cap: usize) -> ChunksOk<Self>
     where
-        Self: Sized + TryStreamExt,
+        Self: Sized,
     {
         ChunksOk::new(self, cap)
     }
@@ -0,0 +1,151 @@
+use alloc::collections::VecDeque;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
+use pin_project_lite::pin_project;
+
+use crate::spawner::SpawnError;
+use crate::spawner::Spawner;
+
+/// A value of the stream, waiting for its turn to be yielded
+enum Slot<H, E> {
+    Task(H),
+    Err(E),
+}
+
+pin_project! {
+    /// Stream for the [`par_map_ok_spawned`](crate::TryStreamies::par_map_ok_spawned) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ParMapOkSpawned<St, S, F, Fut>
+    where
+        St: TryStream,
+        S: Spawner,
+        Fut: Future,
+        Fut::Output: Send,
+        Fut::Output: 'static,
+    {
+        #[pin]
+        stream: St,
+        slots: VecDeque<Slot<S::Handle<Fut::Output>, St::Error>>,
+        limit: usize,
+        stream_done: bool,
+        spawner: S,
+        f: F,
+    }
+}
+
+impl<St, S, F, Fut> ParMapOkSpawned<St, S, F, Fut>
+where
+    St: TryStream,
+    St::Error: From<SpawnError>,
+    S: Spawner,
+    F: FnMut(St::Ok) -> Fut,
+    Fut: Future + Send + 'static,
+    Fut::Output: Send + 'static,
+{
+    pub(crate) fn new(stream: St, limit: usize, spawner: S, f: F) -> Self {
+        assert!(limit > 0, "the limit must be greater than zero");
+
+        Self {
+            stream,
+            slots: VecDeque::with_capacity(limit),
+            limit,
+            stream_done: false,
+            spawner,
+            f,
+        }
+    }
+}
+
+impl<St, S, F, Fut> FusedStream for ParMapOkSpawned<St, S, F, Fut>
+where
+    St: TryStream,
+    St::Error: From<SpawnError>,
+    S: Spawner,
+    F: FnMut(St::Ok) -> Fut,
+    Fut: Future + Send + 'static,
+    Fut::Output: Send + 'static,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream_done && self.slots.is_empty()
+    }
+}
+
+impl<St, S, F, Fut> Stream for ParMapOkSpawned<St, S, F, Fut>
+where
+    St: TryStream,
+    St::Error: From<SpawnError>,
+    S: Spawner,
+    F: FnMut(St::Ok) -> Fut,
+    Fut: Future + Send + 'static,
+    Fut::Output: Send + 'static,
+{
+    type Item = Result<Fut::Output, St::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        // Errors take a slot to keep their position
+        while !*this.stream_done && this.slots.len() < *this.limit {
+            match this.stream.as_mut().try_poll_next(cx) {
+                Poll::Ready(Some(Ok(item))) => {
+                    let task = this.spawner.spawn((this.f)(item));
+                    this.slots.push_back(Slot::Task(task));
+                }
+                Poll::Ready(Some(Err(err))) => this.slots.push_back(Slot::Err(err)),
+                Poll::Ready(None) => *this.stream_done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        // The tasks run on their own, so only the head needs polling
+        let output = match this.slots.front_mut() {
+            Some(Slot::Task(task)) => ready!(Pin::new(task).poll(cx)).map_err(From::from),
+            Some(Slot::Err(_)) => match this.slots.pop_front() {
+                Some(Slot::Err(err)) => return Poll::Ready(Some(Err(err))),
+                _ => unreachable!("the front slot is an error"),
+            },
+            None if *this.stream_done => return Poll::Ready(None),
+            None => return Poll::Pending,
+        };
+
+        this.slots.pop_front();
+        Poll::Ready(Some(output))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.stream_done {
+            return (self.slots.len(), Some(self.slots.len()));
+        }
+
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(self.slots.len()),
+            upper.and_then(|upper| upper.checked_add(self.slots.len())),
+        )
+    }
+}
+
+impl<St, S, F, Fut> fmt::Debug for ParMapOkSpawned<St, S, F, Fut>
+where
+    St: TryStream + fmt::Debug,
+    S: Spawner + fmt::Debug,
+    Fut: Future,
+    Fut::Output: Send + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParMapOkSpawned")
+            .field("stream", &self.stream)
+            .field("in_flight", &self.slots.len())
+            .field("limit", &self.limit)
+            .field("spawner", &self.spawner)
+            .finish_non_exhaustive()
+    }
+}
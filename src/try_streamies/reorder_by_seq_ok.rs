@@ -0,0 +1,162 @@
+use core::fmt;
+use core::ops::Range;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
+use pin_project_lite::pin_project;
+
+use crate::streamies::reorder_by_seq::SeqBuffer;
+use crate::streamies::reorder_by_seq::SeqOutput;
+
+/// Error of the [`ReorderBySeqOk`] stream
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SeqError<E> {
+    /// These sequence numbers never arrived in time, and got skipped
+    Gap {
+        /// The skipped sequence numbers
+        missing: Range<u64>,
+    },
+
+    /// A value with this sequence number was already received, or already skipped. It got dropped
+    Duplicate {
+        /// The sequence number of the dropped value
+        seq: u64,
+    },
+
+    /// The inner stream yielded an error
+    Upstream(E),
+}
+
+impl<E: fmt::Display> fmt::Display for SeqError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Gap { missing } => write!(
+                f,
+                "sequence numbers {} to {} are missing",
+                missing.start,
+                missing.end - 1
+            ),
+            Self::Duplicate { seq } => write!(f, "sequence number {seq} was received twice"),
+            Self::Upstream(err) => err.fmt(f),
+        }
+    }
+}
+
+impl<E> core::error::Error for SeqError<E>
+where
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Upstream(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+pin_project! {
+    /// Stream for the [`reorder_by_seq_ok`](crate::TryStreamies::reorder_by_seq_ok) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ReorderBySeqOk<St, F>
+    where
+        St: TryStream,
+    {
+        #[pin]
+        stream: St,
+        buffer: SeqBuffer<St::Ok>,
+        stream_done: bool,
+        seq_fn: F,
+    }
+}
+
+impl<St, F> ReorderBySeqOk<St, F>
+where
+    St: TryStream,
+    F: FnMut(&St::Ok) -> u64,
+{
+    pub(crate) fn new(stream: St, seq_fn: F, max_gap: u64, max_buffer: usize) -> Self {
+        Self {
+            stream,
+            buffer: SeqBuffer::new(max_gap, max_buffer),
+            stream_done: false,
+            seq_fn,
+        }
+    }
+
+    /// Set the sequence number of the first item, 0 by default
+    pub fn with_start(mut self, start: u64) -> Self {
+        self.buffer.set_start(start);
+        self
+    }
+}
+
+impl<St, F> FusedStream for ReorderBySeqOk<St, F>
+where
+    St: TryStream,
+    F: FnMut(&St::Ok) -> u64,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream_done && self.buffer.is_empty()
+    }
+}
+
+impl<St, F> Stream for ReorderBySeqOk<St, F>
+where
+    St: TryStream,
+    F: FnMut(&St::Ok) -> u64,
+{
+    type Item = Result<St::Ok, SeqError<St::Error>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            match this.buffer.pop(*this.stream_done) {
+                Some(SeqOutput::Item(item)) => return Poll::Ready(Some(Ok(item))),
+                Some(SeqOutput::Gap(missing)) => {
+                    return Poll::Ready(Some(Err(SeqError::Gap { missing })))
+                }
+                None if *this.stream_done => return Poll::Ready(None),
+                None => {}
+            }
+
+            match this.stream.as_mut().try_poll_next(cx) {
+                Poll::Ready(Some(Ok(item))) => {
+                    let seq = (this.seq_fn)(&item);
+                    if this.buffer.push(seq, item).is_err() {
+                        return Poll::Ready(Some(Err(SeqError::Duplicate { seq })));
+                    }
+                }
+                Poll::Ready(Some(Err(err))) => {
+                    return Poll::Ready(Some(Err(SeqError::Upstream(err))))
+                }
+                Poll::Ready(None) => *this.stream_done = true,
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Gaps are extra items, so only the lower bound is known
+        let (lower, _) = self.stream.size_hint();
+        (lower.saturating_add(self.buffer.len()), None)
+    }
+}
+
+impl<St, F> fmt::Debug for ReorderBySeqOk<St, F>
+where
+    St: TryStream + fmt::Debug,
+    St::Ok: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReorderBySeqOk")
+            .field("stream", &self.stream)
+            .field("buffer", &self.buffer)
+            .field("stream_done", &self.stream_done)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,204 @@
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
+use pin_project_lite::pin_project;
+
+use crate::backoff::Backoff;
+use crate::ready_some;
+use crate::timer::Timer;
+
+/// Error of the [`RetryOkWith`] stream.
+///
+/// `E` is the error of the inner stream, and `OpE` the error of the retried operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RetryError<E, OpE = E> {
+    /// The inner stream yielded an error. It isn't retried
+    Upstream(E),
+
+    /// The operation failed on every attempt
+    Exhausted {
+        /// The number of attempts made
+        attempts: u32,
+        /// The error of the last attempt
+        error: OpE,
+    },
+}
+
+impl<E, OpE> fmt::Display for RetryError<E, OpE>
+where
+    E: fmt::Display,
+    OpE: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Upstream(err) => err.fmt(f),
+            Self::Exhausted { attempts, error } => {
+                write!(f, "operation failed after {attempts} attempts: {error}")
+            }
+        }
+    }
+}
+
+impl<E, OpE> core::error::Error for RetryError<E, OpE>
+where
+    E: core::error::Error + 'static,
+    OpE: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Upstream(err) => Some(err),
+            Self::Exhausted { error, .. } => Some(error),
+        }
+    }
+}
+
+pin_project! {
+    /// Stream for the [`retry_ok_with`](crate::TryStreamies::retry_ok_with) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct RetryOkWith<St, T, F, Fut>
+    where
+        St: TryStream,
+        T: Timer,
+    {
+        #[pin]
+        stream: St,
+        #[pin]
+        attempt_fut: Option<Fut>,
+        #[pin]
+        sleep: Option<T::Sleep>,
+        input: Option<St::Ok>,
+        attempt: u32,
+        max_attempts: u32,
+        backoff: Backoff<T>,
+        op: F,
+    }
+}
+
+impl<St, T, F, Fut, O, OpE> RetryOkWith<St, T, F, Fut>
+where
+    St: TryStream,
+    T: Timer,
+    F: FnMut(&St::Ok) -> Fut,
+    Fut: Future<Output = Result<O, OpE>>,
+{
+    pub(crate) fn new(stream: St, max_attempts: u32, backoff: Backoff<T>, op: F) -> Self {
+        assert!(max_attempts > 0, "at least one attempt must be allowed");
+
+        Self {
+            stream,
+            attempt_fut: None,
+            sleep: None,
+            input: None,
+            attempt: 0,
+            max_attempts,
+            backoff,
+            op,
+        }
+    }
+}
+
+impl<St, T, F, Fut, O, OpE> FusedStream for RetryOkWith<St, T, F, Fut>
+where
+    St: TryStream + FusedStream,
+    T: Timer,
+    F: FnMut(&St::Ok) -> Fut,
+    Fut: Future<Output = Result<O, OpE>>,
+{
+    fn is_terminated(&self) -> bool {
+        self.input.is_none() && self.stream.is_terminated()
+    }
+}
+
+impl<St, T, F, Fut, O, OpE> Stream for RetryOkWith<St, T, F, Fut>
+where
+    St: TryStream,
+    T: Timer,
+    F: FnMut(&St::Ok) -> Fut,
+    Fut: Future<Output = Result<O, OpE>>,
+{
+    type Item = Result<O, RetryError<St::Error, OpE>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            // Waiting before the next attempt
+            if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+                ready!(sleep.poll(cx));
+                this.sleep.set(None);
+
+                let input = this
+                    .input
+                    .as_ref()
+                    .expect("a retry is only scheduled with an input");
+                this.attempt_fut.set(Some((this.op)(input)));
+                *this.attempt += 1;
+            }
+
+            if let Some(attempt_fut) = this.attempt_fut.as_mut().as_pin_mut() {
+                let result = ready!(attempt_fut.poll(cx));
+                this.attempt_fut.set(None);
+
+                match result {
+                    Ok(output) => {
+                        *this.input = None;
+                        return Poll::Ready(Some(Ok(output)));
+                    }
+                    Err(_) if this.attempt < this.max_attempts => {
+                        this.sleep.set(Some(this.backoff.sleep(*this.attempt)));
+                        continue;
+                    }
+                    Err(error) => {
+                        *this.input = None;
+                        return Poll::Ready(Some(Err(RetryError::Exhausted {
+                            attempts: *this.attempt,
+                            error,
+                        })));
+                    }
+                }
+            }
+
+            match ready_some!(this.stream.as_mut().try_poll_next(cx)) {
+                Ok(input) => {
+                    this.attempt_fut.set(Some((this.op)(&input)));
+                    *this.input = Some(input);
+                    *this.attempt = 1;
+                }
+                Err(err) => return Poll::Ready(Some(Err(RetryError::Upstream(err)))),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.input.is_some());
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(pending),
+            upper.and_then(|upper| upper.checked_add(pending)),
+        )
+    }
+}
+
+impl<St, T, F, Fut> fmt::Debug for RetryOkWith<St, T, F, Fut>
+where
+    St: TryStream + fmt::Debug,
+    St::Ok: fmt::Debug,
+    T: Timer + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryOkWith")
+            .field("stream", &self.stream)
+            .field("input", &self.input)
+            .field("attempt", &self.attempt)
+            .field("max_attempts", &self.max_attempts)
+            .field("backoff", &self.backoff)
+            .finish_non_exhaustive()
+    }
+}
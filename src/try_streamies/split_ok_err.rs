@@ -0,0 +1,385 @@
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use alloc::task::Wake;
+use core::fmt;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+use core::task::Waker;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
+
+use crate::lock::Lock;
+
+/// Stream of the `Ok` values, for the [`split_ok_err`](crate::TryStreamies::split_ok_err) method.
+///
+/// When the [`SplitErr`] buffer is full, this stream stops polling the inner stream until
+/// the errors get consumed. Dropping the [`SplitErr`] half discards the errors instead.
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use core::task::Poll;
+/// use futures::stream::{self, StreamExt};
+/// use streamies::TryStreamies as _;
+///
+/// let stream = stream::iter(vec![Err("uh oh"), Err("oh no"), Ok(1), Err("again"), Ok(2)]);
+/// let (mut oks, mut errs) = stream.split_ok_err(1);
+///
+/// assert_eq!(futures::poll!(oks.next()), Poll::Pending); // The error buffer is full
+/// assert_eq!(errs.next().await, Some("uh oh"));
+/// assert_eq!(futures::poll!(oks.next()), Poll::Pending);
+/// assert_eq!(errs.next().await, Some("oh no"));
+/// assert_eq!(oks.next().await, Some(1));
+///
+/// drop(errs); // The remaining errors are discarded
+/// assert_eq!(oks.next().await, Some(2));
+/// assert_eq!(oks.next().await, None);
+/// # });
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct SplitOk<St: TryStream> {
+    shared: Arc<Shared<St>>,
+}
+
+/// Stream of the `Err` values, for the [`split_ok_err`](crate::TryStreamies::split_ok_err) method.
+///
+/// When the [`SplitOk`] buffer is full, this stream stops polling the inner stream until
+/// the values get consumed. Dropping the [`SplitOk`] half discards the values instead.
+#[must_use = "streams do nothing unless polled"]
+pub struct SplitErr<St: TryStream> {
+    shared: Arc<Shared<St>>,
+}
+
+pub(crate) fn split_ok_err<St: TryStream>(
+    stream: St,
+    capacity: usize,
+) -> (SplitOk<St>, SplitErr<St>) {
+    assert!(
+        capacity > 0,
+        "the buffer capacity must be greater than zero"
+    );
+
+    let shared = Arc::new(Shared {
+        state: Lock::new(State {
+            stream: Some(Box::pin(stream)),
+            oks: VecDeque::new(),
+            errs: VecDeque::new(),
+            capacity,
+            done: false,
+            missed: false,
+            ok_dropped: false,
+            err_dropped: false,
+        }),
+        wakers: Arc::new(SplitWakers::default()),
+    });
+
+    (
+        SplitOk {
+            shared: shared.clone(),
+        },
+        SplitErr { shared },
+    )
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Ok,
+    Err,
+}
+
+struct Shared<St: TryStream> {
+    state: Lock<State<St>>,
+    wakers: Arc<SplitWakers>,
+}
+
+struct State<St: TryStream> {
+    /// The inner stream is shared by both halves, so it is pinned on the heap. It is taken out of the state
+    /// while a half polls it, so that the lock is never held across the inner poll.
+    stream: Option<Pin<Box<St>>>,
+    oks: VecDeque<St::Ok>,
+    errs: VecDeque<St::Error>,
+    capacity: usize,
+    done: bool,
+    /// The other half got polled while the inner stream was taken out, and has to be woken once it's back
+    missed: bool,
+    ok_dropped: bool,
+    err_dropped: bool,
+}
+
+/// The wakers of both halves. Whichever half polls the inner stream, both get woken up by it.
+///
+/// They are kept out of the state's lock, as the inner stream may wake us while we're polling it.
+#[derive(Default)]
+struct SplitWakers {
+    ok: Lock<Option<Waker>>,
+    err: Lock<Option<Waker>>,
+}
+
+impl SplitWakers {
+    fn slot(&self, side: Side) -> &Lock<Option<Waker>> {
+        match side {
+            Side::Ok => &self.ok,
+            Side::Err => &self.err,
+        }
+    }
+
+    fn register(&self, side: Side, waker: &Waker) {
+        let mut slot = self.slot(side).lock();
+        match &*slot {
+            Some(registered) if registered.will_wake(waker) => {}
+            _ => *slot = Some(waker.clone()),
+        }
+    }
+
+    fn wake_side(&self, side: Side) {
+        let waker = self.slot(side).lock().take();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl Wake for SplitWakers {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.wake_side(Side::Ok);
+        self.wake_side(Side::Err);
+    }
+}
+
+impl<St: TryStream> Shared<St> {
+    fn poll_side(
+        &self,
+        side: Side,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<St::Ok, St::Error>>> {
+        let mut state = self.state.lock();
+
+        // Serve the buffered items first, and let the other half know there's room again
+        let buffered = match side {
+            Side::Ok => state.oks.pop_front().map(Ok),
+            Side::Err => state.errs.pop_front().map(Err),
+        };
+        if let Some(item) = buffered {
+            drop(state);
+            self.wakers.wake_side(side.other());
+            return Poll::Ready(Some(item));
+        }
+
+        if state.done {
+            return Poll::Ready(None);
+        }
+
+        self.wakers.register(side, cx.waker());
+
+        // The other half is polling the inner stream, and will wake us once it's done
+        let Some(mut stream) = state.stream.take() else {
+            state.missed = true;
+            return Poll::Pending;
+        };
+        drop(state);
+
+        let mut wake_other = false;
+        let poll = self.poll_stream(stream.as_mut(), side, &mut wake_other);
+
+        let mut state = self.state.lock();
+        state.stream = Some(stream);
+        wake_other |= core::mem::take(&mut state.missed);
+        drop(state);
+
+        // Wake the other half outside of the lock, as its waker may poll it right away
+        if wake_other {
+            self.wakers.wake_side(side.other());
+        }
+        poll
+    }
+
+    /// Poll the inner stream until an item for `side` comes up, without holding the lock while polling
+    fn poll_stream(
+        &self,
+        mut stream: Pin<&mut St>,
+        side: Side,
+        wake_other: &mut bool,
+    ) -> Poll<Option<Result<St::Ok, St::Error>>> {
+        let waker = Waker::from(self.wakers.clone());
+        let mut inner_cx = Context::from_waker(&waker);
+
+        loop {
+            // Backpressure: don't poll the inner stream until the other half made some room
+            if self.state.lock().is_other_full(side) {
+                return Poll::Pending;
+            }
+
+            let item = match stream.as_mut().try_poll_next(&mut inner_cx) {
+                Poll::Ready(Some(item)) => item,
+                Poll::Ready(None) => {
+                    self.state.lock().done = true;
+                    *wake_other = true;
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => return Poll::Pending,
+            };
+
+            let mut state = self.state.lock();
+            match (side, item) {
+                (Side::Ok, Ok(item)) => return Poll::Ready(Some(Ok(item))),
+                (Side::Err, Err(err)) => return Poll::Ready(Some(Err(err))),
+                (Side::Ok, Err(err)) => {
+                    if !state.err_dropped {
+                        state.errs.push_back(err);
+                        *wake_other = true;
+                    }
+                }
+                (Side::Err, Ok(item)) => {
+                    if !state.ok_dropped {
+                        state.oks.push_back(item);
+                        *wake_other = true;
+                    }
+                }
+            }
+        }
+    }
+
+    fn is_terminated(&self, side: Side) -> bool {
+        let state = self.state.lock();
+        state.done && state.len(side) == 0
+    }
+
+    fn size_hint(&self, side: Side) -> (usize, Option<usize>) {
+        let state = self.state.lock();
+        let buffered = state.len(side);
+
+        if state.done {
+            return (buffered, Some(buffered));
+        }
+
+        // Any of the upcoming items may belong to either half. The inner stream is out while the other half polls it
+        let Some(stream) = &state.stream else {
+            return (buffered, None);
+        };
+        let (_, upper) = stream.size_hint();
+        (
+            buffered,
+            upper.and_then(|upper| upper.checked_add(buffered)),
+        )
+    }
+
+    fn drop_side(&self, side: Side) {
+        let mut state = self.state.lock();
+        match side {
+            Side::Ok => {
+                state.ok_dropped = true;
+                state.oks.clear();
+            }
+            Side::Err => {
+                state.err_dropped = true;
+                state.errs.clear();
+            }
+        }
+        drop(state);
+
+        // The other half may be waiting for us to make some room
+        self.wakers.wake_side(side.other());
+    }
+}
+
+impl<St: TryStream> State<St> {
+    fn len(&self, side: Side) -> usize {
+        match side {
+            Side::Ok => self.oks.len(),
+            Side::Err => self.errs.len(),
+        }
+    }
+
+    fn is_other_full(&self, side: Side) -> bool {
+        match side {
+            Side::Ok => !self.err_dropped && self.errs.len() >= self.capacity,
+            Side::Err => !self.ok_dropped && self.oks.len() >= self.capacity,
+        }
+    }
+}
+
+impl Side {
+    fn other(self) -> Self {
+        match self {
+            Side::Ok => Side::Err,
+            Side::Err => Side::Ok,
+        }
+    }
+}
+
+impl<St: TryStream> Stream for SplitOk<St> {
+    type Item = St::Ok;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.shared.poll_side(Side::Ok, cx).map(|item| {
+            item.map(|item| match item {
+                Ok(item) => item,
+                Err(_) => unreachable!("errors are never yielded to the ok half"),
+            })
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.shared.size_hint(Side::Ok)
+    }
+}
+
+impl<St: TryStream> Stream for SplitErr<St> {
+    type Item = St::Error;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.shared.poll_side(Side::Err, cx).map(|item| {
+            item.map(|item| match item {
+                Err(err) => err,
+                Ok(_) => unreachable!("values are never yielded to the error half"),
+            })
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.shared.size_hint(Side::Err)
+    }
+}
+
+impl<St: TryStream> FusedStream for SplitOk<St> {
+    fn is_terminated(&self) -> bool {
+        self.shared.is_terminated(Side::Ok)
+    }
+}
+
+impl<St: TryStream> FusedStream for SplitErr<St> {
+    fn is_terminated(&self) -> bool {
+        self.shared.is_terminated(Side::Err)
+    }
+}
+
+impl<St: TryStream> Drop for SplitOk<St> {
+    fn drop(&mut self) {
+        self.shared.drop_side(Side::Ok);
+    }
+}
+
+impl<St: TryStream> Drop for SplitErr<St> {
+    fn drop(&mut self) {
+        self.shared.drop_side(Side::Err);
+    }
+}
+
+impl<St: TryStream> fmt::Debug for SplitOk<St> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitOk").finish_non_exhaustive()
+    }
+}
+
+impl<St: TryStream> fmt::Debug for SplitErr<St> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitErr").finish_non_exhaustive()
+    }
+}
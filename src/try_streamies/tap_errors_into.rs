@@ -0,0 +1,232 @@
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+use crate::budget::Budget;
+
+/// What [`TapErrorsInto`] does with an error when the sink isn't ready to receive it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FullSinkPolicy {
+    /// Wait for the sink to be ready, pausing the stream.
+    ///
+    /// The end of the stream also waits for the sink to be flushed. With a bounded channel,
+    /// the receiver must be drained concurrently, or the stream never ends.
+    #[default]
+    Wait,
+
+    /// Drop the error and keep streaming. The end of the stream doesn't wait for the sink to be flushed either
+    DropError,
+}
+
+/// What [`TapErrorsInto`] does when the sink fails
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SinkErrorPolicy {
+    /// Stop sending to the sink, and drop the upcoming errors of the stream
+    #[default]
+    Ignore,
+
+    /// End the stream
+    EndStream,
+}
+
+pin_project! {
+    /// Stream for the [`tap_errors_into`](crate::TryStreamies::tap_errors_into) method.
+    ///
+    /// Once the sink failed, its error can be retrieved with [`sink_error`](TapErrorsInto::sink_error).
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::stream::{self, StreamExt};
+    /// use streamies::{SinkErrorPolicy, TryStreamies as _};
+    ///
+    /// let (tx, mut rx) = mpsc::unbounded();
+    /// rx.close(); // The dead letters can't be received anymore
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Err("uh oh"), Ok(2)]);
+    /// let mut stream = stream.tap_errors_into(tx).on_sink_error(SinkErrorPolicy::EndStream);
+    ///
+    /// assert_eq!(stream.next().await, Some(1));
+    /// assert_eq!(stream.next().await, None);
+    /// assert!(stream.sink_error().unwrap().is_disconnected());
+    /// # });
+    /// ```
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct TapErrorsInto<St, Si>
+    where
+        St: TryStream,
+        Si: Sink<St::Error>
+    {
+        #[pin]
+        stream: St,
+        #[pin]
+        sink: Si,
+        pending: Option<St::Error>,
+        sink_error: Option<Si::Error>,
+        needs_flush: bool,
+        stream_done: bool,
+        done: bool,
+        on_full: FullSinkPolicy,
+        on_sink_error: SinkErrorPolicy,
+        budget: Budget,
+    }
+}
+
+impl<St, Si> TapErrorsInto<St, Si>
+where
+    St: TryStream,
+    Si: Sink<St::Error>,
+{
+    pub(crate) fn new(stream: St, sink: Si) -> Self {
+        Self {
+            stream,
+            sink,
+            pending: None,
+            sink_error: None,
+            needs_flush: false,
+            stream_done: false,
+            done: false,
+            on_full: FullSinkPolicy::default(),
+            on_sink_error: SinkErrorPolicy::default(),
+            budget: Budget::default(),
+        }
+    }
+
+    /// Set what happens to an error when the sink isn't ready. Defaults to [`FullSinkPolicy::Wait`]
+    pub fn on_full(mut self, policy: FullSinkPolicy) -> Self {
+        self.on_full = policy;
+        self
+    }
+
+    /// Set what happens when the sink fails. Defaults to [`SinkErrorPolicy::Ignore`]
+    pub fn on_sink_error(mut self, policy: SinkErrorPolicy) -> Self {
+        self.on_sink_error = policy;
+        self
+    }
+
+    /// Set the cooperative [`Budget`] of the stream
+    pub fn with_budget(mut self, budget: Budget) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    /// The error of the sink, if it failed
+    pub fn sink_error(&self) -> Option<&Si::Error> {
+        self.sink_error.as_ref()
+    }
+}
+
+impl<St, Si> FusedStream for TapErrorsInto<St, Si>
+where
+    St: TryStream,
+    Si: Sink<St::Error>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St, Si> Stream for TapErrorsInto<St, Si>
+where
+    St: TryStream,
+    Si: Sink<St::Error>,
+{
+    type Item = St::Ok;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        let mut remaining = this.budget.start();
+
+        loop {
+            // Hand the previous error to the sink
+            if let Some(err) = this.pending.take() {
+                let sent = match this.sink.as_mut().poll_ready(cx) {
+                    Poll::Ready(Ok(())) => this.sink.as_mut().start_send(err),
+                    Poll::Ready(Err(sink_err)) => Err(sink_err),
+                    Poll::Pending => match this.on_full {
+                        FullSinkPolicy::Wait => {
+                            *this.pending = Some(err);
+                            return Poll::Pending;
+                        }
+                        FullSinkPolicy::DropError => Ok(()),
+                    },
+                };
+
+                match sent {
+                    Ok(()) => *this.needs_flush = true,
+                    Err(sink_err) => {
+                        *this.sink_error = Some(sink_err);
+                        if *this.on_sink_error == SinkErrorPolicy::EndStream {
+                            *this.done = true;
+                            return Poll::Ready(None);
+                        }
+                    }
+                }
+            }
+
+            // Flush without waiting, unless the stream is over and the errors shouldn't be dropped.
+            // Waiting on a bounded channel means waiting for its receiver to make some room
+            if *this.needs_flush && this.sink_error.is_none() {
+                match this.sink.as_mut().poll_flush(cx) {
+                    Poll::Ready(Ok(())) => *this.needs_flush = false,
+                    Poll::Ready(Err(sink_err)) => {
+                        *this.sink_error = Some(sink_err);
+                        if *this.on_sink_error == SinkErrorPolicy::EndStream {
+                            *this.done = true;
+                            return Poll::Ready(None);
+                        }
+                    }
+                    Poll::Pending if *this.stream_done && *this.on_full == FullSinkPolicy::Wait => {
+                        return Poll::Pending
+                    }
+                    Poll::Pending => {}
+                }
+            }
+
+            if *this.stream_done {
+                *this.done = true;
+                return Poll::Ready(None);
+            }
+
+            match this.stream.as_mut().try_poll_next(cx) {
+                Poll::Ready(Some(Ok(item))) => return Poll::Ready(Some(item)),
+                Poll::Ready(Some(Err(err))) => {
+                    // A failed sink won't receive anything anymore
+                    if this.sink_error.is_none() {
+                        *this.pending = Some(err);
+                    }
+                }
+                Poll::Ready(None) => *this.stream_done = true,
+                Poll::Pending => return Poll::Pending,
+            }
+
+            // The error got diverted. Yield back to the executor if we looped for too long
+            if remaining.tick() {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done || self.stream_done {
+            return (0, Some(0));
+        }
+
+        // Any item may be an error
+        let (_, upper) = self.stream.size_hint();
+        (0, upper)
+    }
+}
@@ -0,0 +1,86 @@
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
+use pin_project_lite::pin_project;
+
+use crate::budget::Budget;
+use crate::ready_some;
+
+pin_project! {
+    /// Stream for the [`tap_errors_with`](crate::TryStreamies::tap_errors_with) method.
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct TapErrorsWith<St, F> {
+        #[pin]
+        stream: St,
+        f: F,
+        budget: Budget,
+    }
+}
+
+impl<St, F> TapErrorsWith<St, F>
+where
+    St: TryStream,
+    F: FnMut(St::Error),
+{
+    pub(crate) fn new(stream: St, f: F) -> Self {
+        Self {
+            stream,
+            f,
+            budget: Budget::default(),
+        }
+    }
+
+    /// Set the cooperative [`Budget`] of the stream
+    pub fn with_budget(mut self, budget: Budget) -> Self {
+        self.budget = budget;
+        self
+    }
+}
+
+impl<St, F> FusedStream for TapErrorsWith<St, F>
+where
+    St: TryStream + FusedStream,
+    F: FnMut(St::Error),
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, F> Stream for TapErrorsWith<St, F>
+where
+    St: TryStream,
+    F: FnMut(St::Error),
+{
+    type Item = St::Ok;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        let mut remaining = this.budget.start();
+
+        loop {
+            match ready_some!(this.stream.as_mut().try_poll_next(cx)) {
+                Ok(item) => return Poll::Ready(Some(item)),
+                Err(err) => (this.f)(err),
+            }
+
+            // The error got diverted. Yield back to the executor if we looped for too long
+            if remaining.tick() {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Any item may be an error
+        let (_, upper) = self.stream.size_hint();
+        (0, upper)
+    }
+}
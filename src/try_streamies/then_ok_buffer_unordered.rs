@@ -0,0 +1,120 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Stream for the [`then_ok_buffer_unordered`](crate::TryStreamies::then_ok_buffer_unordered) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ThenOkBufferUnordered<St, F, Fut> {
+        #[pin]
+        stream: St,
+        in_flight: Vec<Pin<Box<Fut>>>,
+        limit: usize,
+        outer_done: bool,
+        f: F,
+    }
+}
+
+impl<St, F, Fut, T> ThenOkBufferUnordered<St, F, Fut>
+where
+    St: TryStream,
+    F: FnMut(St::Ok) -> Fut,
+    Fut: Future<Output = Result<T, St::Error>>,
+{
+    pub(crate) fn new(stream: St, limit: usize, f: F) -> Self {
+        assert!(limit > 0, "the limit must be greater than zero");
+
+        Self {
+            stream,
+            in_flight: Vec::with_capacity(limit),
+            limit,
+            outer_done: false,
+            f,
+        }
+    }
+}
+
+impl<St, F, Fut, T> FusedStream for ThenOkBufferUnordered<St, F, Fut>
+where
+    St: TryStream,
+    F: FnMut(St::Ok) -> Fut,
+    Fut: Future<Output = Result<T, St::Error>>,
+{
+    fn is_terminated(&self) -> bool {
+        self.outer_done && self.in_flight.is_empty()
+    }
+}
+
+impl<St, F, Fut, T> Stream for ThenOkBufferUnordered<St, F, Fut>
+where
+    St: TryStream,
+    F: FnMut(St::Ok) -> Fut,
+    Fut: Future<Output = Result<T, St::Error>>,
+{
+    type Item = Result<T, St::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        // Start new futures while there's room. Errors are yielded as soon as they are received
+        while !*this.outer_done && this.in_flight.len() < *this.limit {
+            match this.stream.as_mut().try_poll_next(cx) {
+                Poll::Ready(Some(Ok(item))) => {
+                    let fut = (this.f)(item);
+                    this.in_flight.push(Box::pin(fut));
+                }
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err))),
+                Poll::Ready(None) => *this.outer_done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        for index in 0..this.in_flight.len() {
+            if let Poll::Ready(output) = this.in_flight[index].as_mut().poll(cx) {
+                drop(this.in_flight.swap_remove(index));
+                return Poll::Ready(Some(output));
+            }
+        }
+
+        if *this.outer_done && this.in_flight.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.outer_done {
+            return (self.in_flight.len(), Some(self.in_flight.len()));
+        }
+
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(self.in_flight.len()),
+            upper.and_then(|upper| upper.checked_add(self.in_flight.len())),
+        )
+    }
+}
+
+impl<St, F, Fut> fmt::Debug for ThenOkBufferUnordered<St, F, Fut>
+where
+    St: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThenOkBufferUnordered")
+            .field("stream", &self.stream)
+            .field("in_flight", &self.in_flight.len())
+            .field("limit", &self.limit)
+            .field("outer_done", &self.outer_done)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,146 @@
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
+use pin_project_lite::pin_project;
+
+/// An item of the stream, waiting for its turn to be yielded
+enum Slot<Fut: Future> {
+    Running(Pin<Box<Fut>>),
+    Done(Fut::Output),
+}
+
+pin_project! {
+    /// Stream for the [`then_ok_buffered`](crate::TryStreamies::then_ok_buffered) method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ThenOkBuffered<St, F, Fut>
+    where
+        Fut: Future,
+    {
+        #[pin]
+        stream: St,
+        slots: VecDeque<Slot<Fut>>,
+        // The number of running slots, which are the only ones counting toward the limit
+        running: usize,
+        limit: usize,
+        outer_done: bool,
+        f: F,
+    }
+}
+
+impl<St, F, Fut, T> ThenOkBuffered<St, F, Fut>
+where
+    St: TryStream,
+    F: FnMut(St::Ok) -> Fut,
+    Fut: Future<Output = Result<T, St::Error>>,
+{
+    pub(crate) fn new(stream: St, limit: usize, f: F) -> Self {
+        assert!(limit > 0, "the limit must be greater than zero");
+
+        Self {
+            stream,
+            slots: VecDeque::with_capacity(limit),
+            running: 0,
+            limit,
+            outer_done: false,
+            f,
+        }
+    }
+}
+
+impl<St, F, Fut, T> FusedStream for ThenOkBuffered<St, F, Fut>
+where
+    St: TryStream,
+    F: FnMut(St::Ok) -> Fut,
+    Fut: Future<Output = Result<T, St::Error>>,
+{
+    fn is_terminated(&self) -> bool {
+        self.outer_done && self.slots.is_empty()
+    }
+}
+
+impl<St, F, Fut, T> Stream for ThenOkBuffered<St, F, Fut>
+where
+    St: TryStream,
+    F: FnMut(St::Ok) -> Fut,
+    Fut: Future<Output = Result<T, St::Error>>,
+{
+    type Item = Result<T, St::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        // Start new futures while there's room. Errors take a slot to keep their position, but don't run
+        while !*this.outer_done && *this.running < *this.limit {
+            match this.stream.as_mut().try_poll_next(cx) {
+                Poll::Ready(Some(Ok(item))) => {
+                    let fut = (this.f)(item);
+                    this.slots.push_back(Slot::Running(Box::pin(fut)));
+                    *this.running += 1;
+                }
+                Poll::Ready(Some(Err(err))) => this.slots.push_back(Slot::Done(Err(err))),
+                Poll::Ready(None) => *this.outer_done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        let was_full = *this.running == *this.limit;
+        for slot in this.slots.iter_mut() {
+            if let Slot::Running(fut) = slot {
+                if let Poll::Ready(output) = fut.as_mut().poll(cx) {
+                    *slot = Slot::Done(output);
+                    *this.running -= 1;
+                }
+            }
+        }
+
+        // A future finished out of order and made room: get polled again to start the next one
+        if was_full && *this.running < *this.limit && !*this.outer_done {
+            cx.waker().wake_by_ref();
+        }
+
+        match this.slots.front() {
+            Some(Slot::Done(_)) => match this.slots.pop_front() {
+                Some(Slot::Done(output)) => Poll::Ready(Some(output)),
+                _ => unreachable!("the front slot is done"),
+            },
+            None if *this.outer_done => Poll::Ready(None),
+            _ => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.outer_done {
+            return (self.slots.len(), Some(self.slots.len()));
+        }
+
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(self.slots.len()),
+            upper.and_then(|upper| upper.checked_add(self.slots.len())),
+        )
+    }
+}
+
+impl<St, F, Fut> fmt::Debug for ThenOkBuffered<St, F, Fut>
+where
+    St: fmt::Debug,
+    Fut: Future,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThenOkBuffered")
+            .field("stream", &self.stream)
+            .field("in_flight", &self.running)
+            .field("buffered", &self.slots.len())
+            .field("limit", &self.limit)
+            .field("outer_done", &self.outer_done)
+            .finish_non_exhaustive()
+    }
+}
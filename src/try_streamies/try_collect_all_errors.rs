@@ -0,0 +1,149 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::Future;
+use core::mem::take;
+use core::pin::Pin;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::future::FusedFuture;
+use futures_core::stream::FusedStream;
+use futures_core::TryStream;
+use pin_project_lite::pin_project;
+
+/// A list of errors, guaranteed to hold at least one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonEmptyErrors<E> {
+    errors: Vec<E>,
+}
+
+// A `NonEmptyErrors` is never empty, an `is_empty` method would always return `false`
+#[allow(clippy::len_without_is_empty)]
+impl<E> NonEmptyErrors<E> {
+    pub(crate) fn new(errors: Vec<E>) -> Self {
+        debug_assert!(!errors.is_empty());
+        Self { errors }
+    }
+
+    /// The first error received
+    pub fn first(&self) -> &E {
+        &self.errors[0]
+    }
+
+    /// The number of errors
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// The errors, in the order they were received
+    pub fn as_slice(&self) -> &[E] {
+        &self.errors
+    }
+
+    /// Take the errors, in the order they were received
+    pub fn into_vec(self) -> Vec<E> {
+        self.errors
+    }
+
+    /// Iterate over the errors, in the order they were received
+    pub fn iter(&self) -> core::slice::Iter<'_, E> {
+        self.errors.iter()
+    }
+}
+
+impl<E> IntoIterator for NonEmptyErrors<E> {
+    type Item = E;
+    type IntoIter = alloc::vec::IntoIter<E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a NonEmptyErrors<E> {
+    type Item = &'a E;
+    type IntoIter = core::slice::Iter<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for NonEmptyErrors<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.errors.len() {
+            1 => self.first().fmt(f),
+            len => write!(f, "{} (and {} more errors)", self.first(), len - 1),
+        }
+    }
+}
+
+impl<E> core::error::Error for NonEmptyErrors<E>
+where
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(self.first())
+    }
+}
+
+pin_project! {
+    /// Future for the [`try_collect_all_errors`](crate::TryStreamies::try_collect_all_errors) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct TryCollectAllErrors<St>
+    where
+        St: TryStream {
+        #[pin]
+        stream: St,
+        items: Vec<St::Ok>,
+        errors: Vec<St::Error>,
+    }
+}
+
+impl<St: TryStream> TryCollectAllErrors<St> {
+    pub(crate) fn new(stream: St) -> Self {
+        Self {
+            stream,
+            items: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+}
+
+impl<St> FusedFuture for TryCollectAllErrors<St>
+where
+    St: TryStream + FusedStream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St> Future for TryCollectAllErrors<St>
+where
+    St: TryStream,
+{
+    type Output = Result<Vec<St::Ok>, NonEmptyErrors<St::Error>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match ready!(this.stream.as_mut().try_poll_next(cx)) {
+                // The values are useless once an error got received
+                Some(Ok(item)) if this.errors.is_empty() => this.items.push(item),
+                Some(Ok(_)) => {}
+                Some(Err(err)) => {
+                    if this.errors.is_empty() {
+                        *this.items = Vec::new();
+                    }
+                    this.errors.push(err);
+                }
+                None if this.errors.is_empty() => return Poll::Ready(Ok(take(this.items))),
+                None => return Poll::Ready(Err(NonEmptyErrors::new(take(this.errors)))),
+            }
+        }
+    }
+}
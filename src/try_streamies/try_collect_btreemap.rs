@@ -0,0 +1,68 @@
+use alloc::collections::BTreeMap;
+use core::future::Future;
+use core::mem::take;
+use core::pin::Pin;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::future::FusedFuture;
+use futures_core::stream::FusedStream;
+use futures_core::TryStream;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`try_collect_btreemap`](crate::TryStreamies::try_collect_btreemap) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct TryCollectBTreeMap<St, K, V> {
+        #[pin]
+        stream: St,
+        map: BTreeMap<K, V>,
+    }
+}
+
+impl<St, K, V> TryCollectBTreeMap<St, K, V>
+where
+    St: TryStream<Ok = (K, V)>,
+    K: Ord,
+{
+    pub(crate) fn new(stream: St) -> Self {
+        Self {
+            stream,
+            map: Default::default(),
+        }
+    }
+}
+
+impl<St, K, V> FusedFuture for TryCollectBTreeMap<St, K, V>
+where
+    St: TryStream<Ok = (K, V)> + FusedStream,
+    K: Ord,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, K, V> Future for TryCollectBTreeMap<St, K, V>
+where
+    St: TryStream<Ok = (K, V)>,
+    K: Ord,
+{
+    type Output = Result<BTreeMap<K, V>, St::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match ready!(this.stream.as_mut().try_poll_next(cx)) {
+                Some(Ok((key, value))) => {
+                    this.map.insert(key, value);
+                }
+                Some(Err(err)) => return Poll::Ready(Err(err)),
+                None => return Poll::Ready(Ok(take(this.map))),
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use core::future::Future;
+use core::hash::Hash;
+use core::mem::take;
+use core::pin::Pin;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::future::FusedFuture;
+use futures_core::stream::FusedStream;
+use futures_core::TryStream;
+use pin_project_lite::pin_project;
+
+use crate::collections::HashSet;
+
+pin_project! {
+    /// Future for the [`try_collect_hashset`](crate::TryStreamies::try_collect_hashset) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct TryCollectHashSet<St>
+    where
+        St: TryStream,
+    {
+        #[pin]
+        stream: St,
+        set: HashSet<St::Ok>,
+    }
+}
+
+impl<St> TryCollectHashSet<St>
+where
+    St: TryStream,
+    St::Ok: Eq + Hash,
+{
+    pub(crate) fn new(stream: St) -> Self {
+        Self {
+            stream,
+            set: Default::default(),
+        }
+    }
+}
+
+impl<St> FusedFuture for TryCollectHashSet<St>
+where
+    St: TryStream + FusedStream,
+    St::Ok: Eq + Hash,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St> Future for TryCollectHashSet<St>
+where
+    St: TryStream,
+    St::Ok: Eq + Hash,
+{
+    type Output = Result<HashSet<St::Ok>, St::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match ready!(this.stream.as_mut().try_poll_next(cx)) {
+                Some(Ok(item)) => {
+                    this.set.insert(item);
+                }
+                Some(Err(err)) => return Poll::Ready(Err(err)),
+                None => return Poll::Ready(Ok(take(this.set))),
+            }
+        }
+    }
+}
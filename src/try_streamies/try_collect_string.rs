@@ -0,0 +1,66 @@
+use alloc::string::String;
+use core::future::Future;
+use core::mem::take;
+use core::pin::Pin;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::future::FusedFuture;
+use futures_core::stream::FusedStream;
+use futures_core::TryStream;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`try_collect_string`](crate::TryStreamies::try_collect_string) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct TryCollectString<St> {
+        #[pin]
+        stream: St,
+        string: String,
+    }
+}
+
+impl<St> TryCollectString<St>
+where
+    St: TryStream,
+    String: Extend<St::Ok>,
+{
+    pub(crate) fn new(stream: St) -> Self {
+        Self {
+            stream,
+            string: Default::default(),
+        }
+    }
+}
+
+impl<St> FusedFuture for TryCollectString<St>
+where
+    St: TryStream + FusedStream,
+    String: Extend<St::Ok>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St> Future for TryCollectString<St>
+where
+    St: TryStream,
+    String: Extend<St::Ok>,
+{
+    type Output = Result<String, St::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match ready!(this.stream.as_mut().try_poll_next(cx)) {
+                Some(Ok(item)) => this.string.extend(Some(item)),
+                Some(Err(err)) => return Poll::Ready(Err(err)),
+                None => return Poll::Ready(Ok(take(this.string))),
+            }
+        }
+    }
+}
@@ -1,28 +1,42 @@
+use alloc::vec::Vec;
 use core::future::Future;
+use core::mem::take;
 use core::pin::Pin;
+use core::task::ready;
 use core::task::Context;
 use core::task::Poll;
 
-use futures::future::FusedFuture;
-use futures::stream::FusedStream;
-use futures::stream::TryCollect;
-use futures::TryStream;
+use futures_core::future::FusedFuture;
+use futures_core::stream::FusedStream;
+use futures_core::TryStream;
 use pin_project_lite::pin_project;
 
 pin_project! {
+    /// Future for the [`try_collect_vec`](crate::TryStreamies::try_collect_vec) method.
     #[derive(Debug)]
     #[must_use = "futures do nothing unless you `.await` or poll them"]
     pub struct TryCollectVec<St>
     where
         St: TryStream {
         #[pin]
-        stream: TryCollect<St, Vec<St::Ok>>,
+        stream: St,
+        items: Vec<St::Ok>,
     }
 }
 
 impl<St: TryStream> TryCollectVec<St> {
-    pub(crate) fn new(stream: TryCollect<St, Vec<St::Ok>>) -> Self {
-        Self { stream }
+    pub(crate) fn new(stream: St) -> Self {
+        Self {
+            stream,
+            items: Vec::new(),
+        }
+    }
+
+    pub(crate) fn with_capacity(stream: St, capacity: usize) -> Self {
+        Self {
+            stream,
+            items: Vec::with_capacity(capacity),
+        }
     }
 }
 
@@ -41,8 +55,15 @@ where
 {
     type Output = Result<Vec<St::Ok>, St::Error>;
 
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let this = self.as_mut().project();
-        this.stream.poll(cx)
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match ready!(this.stream.as_mut().try_poll_next(cx)) {
+                Some(Ok(item)) => this.items.push(item),
+                Some(Err(err)) => return Poll::Ready(Err(err)),
+                None => return Poll::Ready(Ok(take(this.items))),
+            }
+        }
     }
 }
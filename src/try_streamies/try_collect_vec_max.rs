@@ -0,0 +1,128 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::Future;
+use core::mem::take;
+use core::pin::Pin;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::future::FusedFuture;
+use futures_core::TryStream;
+use pin_project_lite::pin_project;
+
+use crate::streamies::collect_vec_max::CollectLimitExceeded;
+
+/// Error of the [`TryCollectVecMax`] future
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryCollectLimitError<T, E> {
+    /// The stream yielded more than the limit
+    LimitExceeded(CollectLimitExceeded<T>),
+
+    /// The stream yielded an error
+    Stream(E),
+}
+
+impl<T, E: fmt::Display> fmt::Display for TryCollectLimitError<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LimitExceeded(err) => err.fmt(f),
+            Self::Stream(err) => err.fmt(f),
+        }
+    }
+}
+
+impl<T, E> core::error::Error for TryCollectLimitError<T, E>
+where
+    T: fmt::Debug,
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::LimitExceeded(_) => None,
+            Self::Stream(err) => Some(err),
+        }
+    }
+}
+
+pin_project! {
+    /// Future for the [`try_collect_vec_max`](crate::TryStreamies::try_collect_vec_max) and
+    /// [`try_collect_vec_max_weight`](crate::TryStreamies::try_collect_vec_max_weight) methods.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct TryCollectVecMax<St, F>
+    where
+        St: TryStream
+    {
+        #[pin]
+        stream: St,
+        items: Vec<St::Ok>,
+        weight: usize,
+        limit: usize,
+        weight_fn: F,
+        done: bool,
+    }
+}
+
+impl<St, F> TryCollectVecMax<St, F>
+where
+    St: TryStream,
+    F: FnMut(&St::Ok) -> usize,
+{
+    pub(crate) fn new(stream: St, limit: usize, weight_fn: F) -> Self {
+        Self {
+            stream,
+            items: Vec::new(),
+            weight: 0,
+            limit,
+            weight_fn,
+            done: false,
+        }
+    }
+}
+
+impl<St, F> FusedFuture for TryCollectVecMax<St, F>
+where
+    St: TryStream,
+    F: FnMut(&St::Ok) -> usize,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St, F> Future for TryCollectVecMax<St, F>
+where
+    St: TryStream,
+    F: FnMut(&St::Ok) -> usize,
+{
+    type Output = Result<Vec<St::Ok>, TryCollectLimitError<St::Ok, St::Error>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            let item = match ready!(this.stream.as_mut().try_poll_next(cx)) {
+                Some(Ok(item)) => item,
+                Some(Err(err)) => {
+                    *this.done = true;
+                    return Poll::Ready(Err(TryCollectLimitError::Stream(err)));
+                }
+                None => {
+                    *this.done = true;
+                    return Poll::Ready(Ok(take(this.items)));
+                }
+            };
+
+            *this.weight = this.weight.saturating_add((this.weight_fn)(&item));
+            this.items.push(item);
+
+            if this.weight > this.limit {
+                *this.done = true;
+                return Poll::Ready(Err(TryCollectLimitError::LimitExceeded(
+                    CollectLimitExceeded::new(*this.limit, take(this.items)),
+                )));
+            }
+        }
+    }
+}
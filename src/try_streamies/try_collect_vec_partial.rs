@@ -0,0 +1,69 @@
+use alloc::vec::Vec;
+use core::future::Future;
+use core::mem::take;
+use core::pin::Pin;
+use core::task::ready;
+use core::task::Context;
+use core::task::Poll;
+
+use futures_core::future::FusedFuture;
+use futures_core::TryStream;
+use pin_project_lite::pin_project;
+
+pin_project! {
+    /// Future for the [`try_collect_vec_partial`](crate::TryStreamies::try_collect_vec_partial) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct TryCollectVecPartial<St>
+    where
+        St: TryStream {
+        #[pin]
+        stream: St,
+        items: Vec<St::Ok>,
+        done: bool,
+    }
+}
+
+impl<St: TryStream> TryCollectVecPartial<St> {
+    pub(crate) fn new(stream: St) -> Self {
+        Self {
+            stream,
+            items: Vec::new(),
+            done: false,
+        }
+    }
+}
+
+impl<St> FusedFuture for TryCollectVecPartial<St>
+where
+    St: TryStream,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St> Future for TryCollectVecPartial<St>
+where
+    St: TryStream,
+{
+    type Output = (Vec<St::Ok>, Option<St::Error>);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match ready!(this.stream.as_mut().try_poll_next(cx)) {
+                Some(Ok(item)) => this.items.push(item),
+                Some(Err(err)) => {
+                    *this.done = true;
+                    return Poll::Ready((take(this.items), Some(err)));
+                }
+                None => {
+                    *this.done = true;
+                    return Poll::Ready((take(this.items), None));
+                }
+            }
+        }
+    }
+}
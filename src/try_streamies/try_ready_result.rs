@@ -1,22 +1,48 @@
+use alloc::vec::Vec;
+use core::mem::take;
 use core::pin::Pin;
 use core::task::Context;
 use core::task::Poll;
 
-use futures::stream::FusedStream;
-use futures::Stream;
-use futures::TryStream;
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
 use pin_project_lite::pin_project;
 
+use crate::budget::Budget;
+
 pin_project! {
-    /// Stream for the [`merge_round_robin`](crate::Streamies::merge_round_robin) method.
+    /// Stream for the [`ready_chunks_ok`](crate::TryStreamies::ready_chunks_ok) method.
+    ///
+    /// The stream is only terminated once the inner stream is terminated *and*
+    /// the error held back for the next poll has been yielded.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, FusedStream, Stream, StreamExt};
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let mut stream = stream::iter(vec![Ok(1), Err(2)]).fuse().ready_chunks_ok(2);
+    /// assert_eq!(stream.size_hint(), (1, Some(2)));
+    ///
+    /// assert_eq!(stream.next().await, Some(Ok(vec![1])));
+    /// assert!(!stream.is_terminated()); // The error is still pending
+    /// assert_eq!(stream.next().await, Some(Err(2)));
+    /// assert_eq!(stream.next().await, None);
+    /// assert!(stream.is_terminated());
+    /// assert_eq!(stream.next().await, None);
+    /// # });
+    /// ```
     #[derive(Debug)]
     #[must_use = "streams do nothing unless polled"]
     pub struct ReadyChunksOk<St> where St: TryStream{
         #[pin]
         stream: St,
+        items: Vec<St::Ok>,
         cap: usize,
         error: Option<St::Error>,
         done: bool,
+        budget: Budget,
     }
 }
 
@@ -24,14 +50,26 @@ impl<St> ReadyChunksOk<St>
 where
     St: TryStream,
 {
-    pub(super) fn new(stream: St, cap: usize) -> Self {
+    pub(crate) fn new(stream: St, cap: usize) -> Self {
+        assert!(cap > 0);
+
         Self {
             stream,
+            items: Vec::new(),
             cap,
             error: None,
             done: false,
+            budget: Budget::default(),
         }
     }
+
+    /// Set the cooperative [`Budget`] of the stream.
+    ///
+    /// Once the budget is spent, the stream yields back to the executor and keeps the partial chunk for the next poll
+    pub fn with_budget(mut self, budget: Budget) -> Self {
+        self.budget = budget;
+        self
+    }
 }
 
 impl<St> FusedStream for ReadyChunksOk<St>
@@ -39,7 +77,7 @@ where
     St: FusedStream + TryStream + Stream<Item = Result<St::Ok, St::Error>>,
 {
     fn is_terminated(&self) -> bool {
-        self.stream.is_terminated()
+        self.error.is_none() && (self.done || self.stream.is_terminated())
     }
 }
 
@@ -61,17 +99,17 @@ where
             return Poll::Ready(None);
         }
 
-        let mut items = Vec::new();
+        let mut remaining = this.budget.start();
 
         loop {
             match this.stream.as_mut().poll_next(cx) {
                 // Flush all collected data if underlying stream doesn't contain
                 // more ready values
                 Poll::Pending => {
-                    return if items.is_empty() {
+                    return if this.items.is_empty() {
                         Poll::Pending
                     } else {
-                        Poll::Ready(Some(Ok(items)))
+                        Poll::Ready(Some(Ok(take(this.items))))
                     }
                 }
 
@@ -79,47 +117,67 @@ where
                 // If so, replace our buffer with a new and empty one and return
                 // the full one.
                 Poll::Ready(Some(Ok(item))) => {
-                    if items.is_empty() {
-                        items.reserve(*this.cap);
+                    if this.items.is_empty() {
+                        this.items.reserve(*this.cap);
                     }
-                    items.push(item);
-                    if items.len() >= *this.cap {
-                        return Poll::Ready(Some(Ok(items)));
+                    this.items.push(item);
+                    if this.items.len() >= *this.cap {
+                        return Poll::Ready(Some(Ok(take(this.items))));
                     }
                 }
 
                 // Found an error! If we got items, we store it for next poll, and return our items
                 // Or else we return the error directly
                 Poll::Ready(Some(Err(item))) => {
-                    if items.is_empty() {
+                    if this.items.is_empty() {
                         return Poll::Ready(Some(Err(item)));
                     }
                     let _ = this.error.insert(item); // The previous error should be yielded earlier
 
-                    return Poll::Ready(Some(Ok(items)));
+                    return Poll::Ready(Some(Ok(take(this.items))));
                 }
 
                 // Got a None. The stream is finished, so if we got values, we return them
                 Poll::Ready(None) => {
-                    if items.is_empty() {
+                    if this.items.is_empty() {
                         return Poll::Ready(None);
                     }
 
                     // Flag the stream as over
                     *this.done = true;
 
-                    return Poll::Ready(Some(Ok(items)));
+                    return Poll::Ready(Some(Ok(take(this.items))));
                 }
             }
+
+            // Yield back to the executor if we looped for too long. The partial chunk is kept,
+            // so the budget never cuts it short
+            if remaining.tick() {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        if self.error.is_some() {
-            let (lower, upper) = self.stream.size_hint();
-            (lower + 1, upper.map(|v| v + 1))
-        } else {
-            self.stream.size_hint()
+        let pending_error = usize::from(self.error.is_some());
+
+        if self.done {
+            return (pending_error, Some(pending_error));
         }
+
+        // At worst, every item is yielded on its own. At best, they all are `Ok`
+        // values and get packed into full chunks
+        let buffered = self.items.len();
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower
+                .saturating_add(buffered)
+                .div_ceil(self.cap)
+                .saturating_add(pending_error),
+            upper
+                .and_then(|upper| upper.checked_add(usize::from(buffered > 0)))
+                .and_then(|upper| upper.checked_add(pending_error)),
+        )
     }
 }
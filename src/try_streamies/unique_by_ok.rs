@@ -2,31 +2,53 @@ use core::hash::Hash;
 use core::pin::Pin;
 use core::task::Context;
 use core::task::Poll;
-use std::collections::hash_map::Entry;
-use std::collections::HashMap;
 
-use futures::stream::FusedStream;
-use futures::Stream;
-use futures::TryStream;
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
 use pin_project_lite::pin_project;
 
+use crate::budget::Budget;
+use crate::collections::Entry;
+use crate::collections::HashMap;
 use crate::ready_some;
 use crate::ready_some_ok;
 
 pin_project! {
-    /// Stream for the [`merge_round_robin`](crate::Streamies::merge_round_robin) method.
+    /// Stream for the [`unique_by_ok`](crate::TryStreamies::unique_by_ok) method.
+    ///
+    /// As duplicates are skipped, only the first item is guaranteed to be yielded
+    /// if the inner stream has any.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, FusedStream, Stream, StreamExt};
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let mut stream = stream::iter(vec![Ok::<_, ()>(1), Ok(2), Ok(1)]).fuse().unique_by_ok(|v| *v);
+    /// assert_eq!(stream.size_hint(), (1, Some(3)));
+    ///
+    /// assert_eq!(stream.next().await, Some(Ok(1)));
+    /// assert_eq!(stream.size_hint(), (0, Some(2)));
+    /// assert_eq!(stream.next().await, Some(Ok(2)));
+    /// assert_eq!(stream.next().await, None);
+    /// assert!(stream.is_terminated());
+    /// assert_eq!(stream.next().await, None);
+    /// # });
+    /// ```
     #[derive(Debug)]
     #[must_use = "streams do nothing unless polled"]
     pub struct UniqueByOk<St: Stream, V, F> {
         #[pin]
         stream: St,
         used: HashMap<V, ()>,
+        budget: Budget,
         f: F,
     }
 }
 
 impl<St: TryStream, V, F> UniqueByOk<St, V, F> {
-    pub(super) fn new(stream: St, f: F) -> Self
+    pub(crate) fn new(stream: St, f: F) -> Self
     where
         V: Eq + Hash,
 
@@ -35,9 +57,16 @@ impl<St: TryStream, V, F> UniqueByOk<St, V, F> {
         Self {
             stream,
             used: Default::default(),
+            budget: Budget::default(),
             f,
         }
     }
+
+    /// Set the cooperative [`Budget`] of the stream
+    pub fn with_budget(mut self, budget: Budget) -> Self {
+        self.budget = budget;
+        self
+    }
 }
 
 impl<St: Stream, V, F> FusedStream for UniqueByOk<St, V, F>
@@ -63,6 +92,8 @@ where
         let mut this = self.project();
         let f = this.f;
 
+        let mut remaining = this.budget.start();
+
         loop {
             let item = ready_some_ok!(this.stream.as_mut().try_poll_next(cx));
 
@@ -72,10 +103,18 @@ where
                 e.insert(());
                 return Poll::Ready(Some(Ok(item)));
             }
+
+            // The item was a duplicate. Yield back to the executor if we looped for too long
+            if remaining.tick() {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.stream.size_hint()
+        // Any item after the first one may be a duplicate
+        let (lower, upper) = self.stream.size_hint();
+        (usize::from(lower > 0 && self.used.is_empty()), upper)
     }
 }
@@ -2,38 +2,67 @@ use core::hash::Hash;
 use core::pin::Pin;
 use core::task::Context;
 use core::task::Poll;
-use std::collections::hash_map::Entry;
-use std::collections::HashMap;
 
-use futures::stream::FusedStream;
-use futures::Stream;
-use futures::TryStream;
+use futures_core::stream::FusedStream;
+use futures_core::Stream;
+use futures_core::TryStream;
 use pin_project_lite::pin_project;
 
+use crate::budget::Budget;
+use crate::collections::Entry;
+use crate::collections::HashMap;
 use crate::ready_some;
 use crate::ready_some_ok;
 
 pin_project! {
-    /// Stream for the [`merge_round_robin`](crate::Streamies::merge_round_robin) method.
+    /// Stream for the [`unique_ok`](crate::TryStreamies::unique_ok) method.
+    ///
+    /// As duplicates are skipped, only the first item is guaranteed to be yielded
+    /// if the inner stream has any.
+    ///
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, FusedStream, Stream, StreamExt};
+    /// use streamies::TryStreamies as _;
+    ///
+    /// let mut stream = stream::iter(vec![Ok::<_, ()>(1), Ok(2), Ok(1)]).fuse().unique_ok();
+    /// assert_eq!(stream.size_hint(), (1, Some(3)));
+    ///
+    /// assert_eq!(stream.next().await, Some(Ok(1)));
+    /// assert_eq!(stream.size_hint(), (0, Some(2)));
+    /// assert_eq!(stream.next().await, Some(Ok(2)));
+    /// assert_eq!(stream.next().await, None);
+    /// assert!(stream.is_terminated());
+    /// assert_eq!(stream.next().await, None);
+    /// # });
+    /// ```
     #[derive(Debug)]
     #[must_use = "streams do nothing unless polled"]
     pub struct UniqueOk<St: TryStream> {
         #[pin]
         stream: St,
         used: HashMap<St::Ok, ()>,
+        budget: Budget,
     }
 }
 
 impl<St: TryStream> UniqueOk<St> {
-    pub(super) fn new(stream: St) -> Self
+    pub(crate) fn new(stream: St) -> Self
     where
         St::Ok: Eq + Hash,
     {
         Self {
             stream,
             used: Default::default(),
+            budget: Budget::default(),
         }
     }
+
+    /// Set the cooperative [`Budget`] of the stream
+    pub fn with_budget(mut self, budget: Budget) -> Self {
+        self.budget = budget;
+        self
+    }
 }
 
 impl<St> FusedStream for UniqueOk<St>
@@ -56,6 +85,8 @@ where
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let mut this = self.project();
 
+        let mut remaining = this.budget.start();
+
         loop {
             let item = ready_some_ok!(this.stream.as_mut().try_poll_next(cx));
 
@@ -64,10 +95,18 @@ where
                 e.insert(());
                 return Poll::Ready(Some(Ok(elt)));
             }
+
+            // The item was a duplicate. Yield back to the executor if we looped for too long
+            if remaining.tick() {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.stream.size_hint()
+        // Any item after the first one may be a duplicate
+        let (lower, upper) = self.stream.size_hint();
+        (usize::from(lower > 0 && self.used.is_empty()), upper)
     }
 }
@@ -0,0 +1,80 @@
+//! Configuration checks and half-open behaviour of `circuit_breaker`.
+
+use core::time::Duration;
+
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt as _};
+use streamies::timer::ManualClock;
+use streamies::{CircuitBreakerConfig, CircuitBreakerError, CircuitState, TryStreamies as _};
+
+#[test]
+#[should_panic = "the failure rate must be in (0.0, 1.0]"]
+fn zero_failure_rate_panics() {
+    let _ = CircuitBreakerConfig::new(ManualClock::new()).failure_rate(0.0);
+}
+
+#[test]
+#[should_panic = "the failure rate must be in (0.0, 1.0]"]
+fn nan_failure_rate_panics() {
+    let _ = CircuitBreakerConfig::new(ManualClock::new()).failure_rate(f64::NAN);
+}
+
+#[test]
+#[should_panic = "the failure rate must be in (0.0, 1.0]"]
+fn failure_rate_above_one_panics() {
+    let _ = CircuitBreakerConfig::new(ManualClock::new()).failure_rate(1.5);
+}
+
+#[test]
+fn half_open_trials() {
+    block_on(async {
+        let clock = ManualClock::new();
+        let config = CircuitBreakerConfig::new(clock.clone())
+            .window(2)
+            .cooldown(Duration::from_secs(10))
+            .half_open_successes(2);
+
+        let stream = stream::iter(vec![
+            Err("down"),
+            Err("down"),
+            Ok(1),
+            Err("down"),
+            Ok(2),
+            Ok(3),
+            Ok(4),
+            Ok(5),
+        ]);
+        let mut stream = stream.circuit_breaker(config);
+        let handle = stream.handle();
+
+        stream.next().await;
+        stream.next().await;
+        assert_eq!(handle.state(), CircuitState::Open);
+
+        // The state is only updated on the next poll
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(handle.state(), CircuitState::Open);
+
+        // A failure during the trials opens the circuit again
+        assert_eq!(stream.next().await, Some(Ok(1)));
+        assert_eq!(handle.state(), CircuitState::HalfOpen);
+        assert_eq!(
+            stream.next().await,
+            Some(Err(CircuitBreakerError::Upstream("down")))
+        );
+        assert_eq!(handle.state(), CircuitState::Open);
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(CircuitBreakerError::Open(_)))
+        ));
+
+        // Enough successes in a row close it
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(stream.next().await, Some(Ok(3)));
+        assert_eq!(handle.state(), CircuitState::HalfOpen);
+        assert_eq!(stream.next().await, Some(Ok(4)));
+        assert_eq!(handle.state(), CircuitState::Closed);
+        assert_eq!(stream.next().await, Some(Ok(5)));
+        assert_eq!(handle.metrics().opened, 2);
+    });
+}
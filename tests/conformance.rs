@@ -0,0 +1,342 @@
+//! Drive the adaptors on random inputs, and check them against reference implementations built on iterators.
+//!
+//! For every adaptor, the harness checks that:
+//! - the items are the same as the reference
+//! - `size_hint` always contains the number of remaining items
+//! - `is_terminated` is `false` while items remain, and `true` once `None` got returned
+//! - polling again after `None` keeps returning `None`
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::future::FutureExt as _;
+use futures::stream::{self, FusedStream, Stream, StreamExt as _};
+use futures::task::noop_waker_ref;
+use streamies::TryStreamies as _;
+
+const ROUNDS: u64 = 200;
+
+/// Small xorshift generator, so the inputs are random but reproducible
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    /// A few small values, so duplicates are frequent
+    fn values(&mut self) -> Vec<u8> {
+        let len = self.below(40);
+        (0..len).map(|_| self.below(8) as u8).collect()
+    }
+
+    /// Values with roughly one error out of four
+    fn results(&mut self) -> Vec<Result<u8, u8>> {
+        self.values()
+            .into_iter()
+            .map(|value| {
+                if self.below(4) == 0 {
+                    Err(value)
+                } else {
+                    Ok(value)
+                }
+            })
+            .collect()
+    }
+
+    fn nested<T>(&mut self, mut item: impl FnMut(&mut Self) -> T) -> Vec<Result<Vec<T>, u8>> {
+        let len = self.below(10);
+        (0..len)
+            .map(|_| {
+                if self.below(4) == 0 {
+                    Err(self.below(8) as u8)
+                } else {
+                    let len = self.below(5);
+                    Ok((0..len).map(|_| item(self)).collect())
+                }
+            })
+            .collect()
+    }
+}
+
+/// Poll the stream to the end, checking its fused behaviour and its size hint against the expected item count
+fn drive<St>(mut stream: St, expected: usize) -> Vec<St::Item>
+where
+    St: FusedStream + Unpin,
+{
+    let mut cx = Context::from_waker(noop_waker_ref());
+    let mut items = Vec::new();
+
+    loop {
+        let remaining = expected.saturating_sub(items.len());
+        let (lower, upper) = stream.size_hint();
+        assert!(
+            lower <= remaining,
+            "lower bound {lower} above the {remaining} remaining items"
+        );
+        assert!(
+            upper.is_none_or(|upper| upper >= remaining),
+            "upper bound {upper:?} below the {remaining} remaining items"
+        );
+        if remaining > 0 {
+            assert!(
+                !stream.is_terminated(),
+                "terminated with {remaining} remaining items"
+            );
+        }
+
+        match poll_ready(&mut stream, &mut cx) {
+            Some(item) => {
+                assert!(items.len() < expected, "more items than the reference");
+                items.push(item);
+            }
+            None => break,
+        }
+    }
+
+    assert_eq!(items.len(), expected, "fewer items than the reference");
+    assert!(
+        stream.is_terminated(),
+        "not terminated after returning None"
+    );
+    assert_eq!(
+        stream.size_hint(),
+        (0, Some(0)),
+        "size hint of a terminated stream"
+    );
+
+    for _ in 0..3 {
+        assert!(
+            poll_ready(&mut stream, &mut cx).is_none(),
+            "polled again after None"
+        );
+        assert!(stream.is_terminated());
+    }
+
+    items
+}
+
+/// The sources never return `Pending`, so the adaptors only return it to yield back to the executor
+fn poll_ready<St: Stream + Unpin>(stream: &mut St, cx: &mut Context<'_>) -> Option<St::Item> {
+    for _ in 0..1000 {
+        if let Poll::Ready(item) = Pin::new(&mut *stream).poll_next(cx) {
+            return item;
+        }
+    }
+
+    panic!("the stream never became ready")
+}
+
+fn source<T>(items: Vec<T>) -> stream::Fuse<stream::Iter<std::vec::IntoIter<T>>> {
+    stream::iter(items).fuse()
+}
+
+fn reference_chunks(input: &[Result<u8, u8>], cap: usize) -> Vec<Result<Vec<u8>, u8>> {
+    let mut output = Vec::new();
+    let mut chunk = Vec::new();
+
+    for item in input {
+        match item {
+            Ok(value) => {
+                chunk.push(*value);
+                if chunk.len() == cap {
+                    output.push(Ok(std::mem::take(&mut chunk)));
+                }
+            }
+            Err(err) => {
+                if !chunk.is_empty() {
+                    output.push(Ok(std::mem::take(&mut chunk)));
+                }
+                output.push(Err(*err));
+            }
+        }
+    }
+
+    if !chunk.is_empty() {
+        output.push(Ok(chunk));
+    }
+    output
+}
+
+#[test]
+fn chunks_ok() {
+    for seed in 0..ROUNDS {
+        let mut rng = Rng::new(seed);
+        let input = rng.results();
+        let cap = rng.below(5) as usize + 1;
+
+        let expected = reference_chunks(&input, cap);
+        let output = drive(source(input).chunks_ok(cap), expected.len());
+        assert_eq!(output, expected, "seed {seed}");
+    }
+}
+
+#[test]
+fn ready_chunks_ok() {
+    // The source is always ready, so the chunks are only cut by their capacity and the errors
+    for seed in 0..ROUNDS {
+        let mut rng = Rng::new(seed);
+        let input = rng.results();
+        let cap = rng.below(5) as usize + 1;
+
+        let expected = reference_chunks(&input, cap);
+        let output = drive(source(input).ready_chunks_ok(cap), expected.len());
+        assert_eq!(output, expected, "seed {seed}");
+    }
+}
+
+#[test]
+fn flatten_ok_iter() {
+    for seed in 0..ROUNDS {
+        let mut rng = Rng::new(seed);
+        let input = rng.nested(|rng| rng.below(8) as u8);
+
+        let expected: Vec<Result<u8, u8>> = input
+            .iter()
+            .flat_map(|item| match item {
+                Ok(values) => values.iter().copied().map(Ok).collect(),
+                Err(err) => vec![Err(*err)],
+            })
+            .collect();
+        let output = drive(source(input).flatten_ok_iter(), expected.len());
+        assert_eq!(output, expected, "seed {seed}");
+    }
+}
+
+#[test]
+fn flatten_ok_stream() {
+    for seed in 0..ROUNDS {
+        let mut rng = Rng::new(seed);
+        let input = rng.nested(|rng| rng.below(8) as u8);
+
+        let expected: Vec<Result<u8, u8>> = input
+            .iter()
+            .flat_map(|item| match item {
+                Ok(values) => values.iter().copied().map(Ok).collect(),
+                Err(err) => vec![Err(*err)],
+            })
+            .collect();
+        let input = input
+            .into_iter()
+            .map(|item| item.map(stream::iter))
+            .collect();
+        let output = drive(source(input).flatten_ok_stream(), expected.len());
+        assert_eq!(output, expected, "seed {seed}");
+    }
+}
+
+#[test]
+fn flatten_ok_result() {
+    for seed in 0..ROUNDS {
+        let mut rng = Rng::new(seed);
+        let input: Vec<Result<Result<u8, u8>, u8>> = rng
+            .results()
+            .into_iter()
+            .map(|item| {
+                if rng.below(3) == 0 {
+                    item.map(Err)
+                } else {
+                    item.map(Ok)
+                }
+            })
+            .collect();
+
+        let expected: Vec<Result<u8, u8>> = input
+            .iter()
+            .map(|item| item.and_then(|item| item))
+            .collect();
+        let output = drive(source(input).flatten_ok_result(), expected.len());
+        assert_eq!(output, expected, "seed {seed}");
+    }
+}
+
+#[test]
+fn extract_future_ok() {
+    for seed in 0..ROUNDS {
+        let mut rng = Rng::new(seed);
+        let input = rng.results();
+
+        let expected = input.clone();
+        let input = input
+            .into_iter()
+            .map(|item| item.map(futures::future::ready))
+            .collect();
+        let output: Vec<Result<u8, u8>> = drive(source(input).extract_future_ok(), expected.len())
+            .into_iter()
+            .map(|fut| fut.now_or_never().expect("the future is ready"))
+            .collect();
+        assert_eq!(output, expected, "seed {seed}");
+    }
+}
+
+#[test]
+fn unique() {
+    use std::collections::HashSet;
+
+    use streamies::Streamies as _;
+
+    for seed in 0..ROUNDS {
+        let mut rng = Rng::new(seed);
+        let input = rng.values();
+
+        let mut seen = HashSet::new();
+        let expected: Vec<u8> = input
+            .iter()
+            .copied()
+            .filter(|value| seen.insert(*value))
+            .collect();
+        let output = drive(source(input.clone()).unique(), expected.len());
+        assert_eq!(output, expected, "seed {seed}");
+
+        let mut seen = HashSet::new();
+        let expected: Vec<u8> = input
+            .iter()
+            .copied()
+            .filter(|value| seen.insert(value % 3))
+            .collect();
+        let output = drive(source(input).unique_by(|value| value % 3), expected.len());
+        assert_eq!(output, expected, "seed {seed}");
+    }
+}
+
+#[test]
+fn unique_ok() {
+    use std::collections::HashSet;
+
+    for seed in 0..ROUNDS {
+        let mut rng = Rng::new(seed);
+        let input = rng.results();
+
+        let mut seen = HashSet::new();
+        let expected: Vec<Result<u8, u8>> = input
+            .iter()
+            .copied()
+            .filter(|item| item.is_err() || item.is_ok_and(|value| seen.insert(value)))
+            .collect();
+        let output = drive(source(input.clone()).unique_ok(), expected.len());
+        assert_eq!(output, expected, "seed {seed}");
+
+        let mut seen = HashSet::new();
+        let expected: Vec<Result<u8, u8>> = input
+            .iter()
+            .copied()
+            .filter(|item| item.is_err() || item.is_ok_and(|value| seen.insert(value % 3)))
+            .collect();
+        let output = drive(
+            source(input).unique_by_ok(|value| value % 3),
+            expected.len(),
+        );
+        assert_eq!(output, expected, "seed {seed}");
+    }
+}
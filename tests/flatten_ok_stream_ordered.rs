@@ -0,0 +1,59 @@
+//! Memory bounds of `flatten_ok_stream_ordered` while its head stream is stalled.
+
+use std::cell::Cell;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures::channel::mpsc;
+use futures::stream::{self, BoxStream, Stream, StreamExt as _};
+use futures::task::noop_waker_ref;
+use streamies::{Streamies as _, TryStreamies as _};
+
+fn poll<St: Stream + Unpin>(stream: &mut St) -> Poll<Option<St::Item>> {
+    Pin::new(stream).poll_next(&mut Context::from_waker(noop_waker_ref()))
+}
+
+#[test]
+fn stalled_head_bounds_the_buffers() {
+    let (sender, head) = mpsc::unbounded::<u32>();
+    let pulled = Cell::new(0);
+    let buffered = Arc::new(AtomicUsize::new(0));
+
+    // The head is stalled, while an endless supply of errors and ready streams follows it
+    let others = stream::iter(0u32..).map(|i| {
+        if i % 3 == 0 {
+            return Err(i);
+        }
+
+        let buffered = buffered.clone();
+        let inner = stream::iter(0..100).inspect(move |_| {
+            buffered.fetch_add(1, Ordering::Relaxed);
+        });
+        Ok(inner.boxed())
+    });
+    let outer = stream::iter([Ok::<BoxStream<'static, u32>, u32>(head.boxed())])
+        .chain(others)
+        .inspect(|_| pulled.set(pulled.get() + 1));
+    let mut stream = outer.flatten_ok_stream_ordered(4).with_buffer_capacity(3);
+
+    for _ in 0..10 {
+        assert_eq!(poll(&mut stream), Poll::Pending);
+    }
+
+    // The head, an error, and two streams with a full buffer
+    assert_eq!(pulled.get(), 4);
+    assert_eq!(buffered.load(Ordering::Relaxed), 2 * 3);
+
+    // Once the head ends, the others are yielded in order
+    sender.unbounded_send(7).unwrap();
+    drop(sender);
+    assert_eq!(poll(&mut stream), Poll::Ready(Some(Ok(7))));
+    assert_eq!(poll(&mut stream), Poll::Ready(Some(Err(0))));
+
+    let items = stream.take(200).collect_vec();
+    let items = futures::executor::block_on(items);
+    let expected: Vec<_> = (0..100).map(Ok).chain((0..100).map(Ok)).collect();
+    assert_eq!(items, expected);
+}
@@ -0,0 +1,61 @@
+//! Waking of the sleeps of `ManualClock`.
+
+use core::time::Duration;
+use std::future::Future;
+use std::pin::pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+use streamies::timer::{ManualClock, Timer};
+
+#[derive(Default)]
+struct CountWakes(AtomicUsize);
+
+impl Wake for CountWakes {
+    fn wake(self: Arc<Self>) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn advance_wakes_expired_sleeps() {
+    let clock = ManualClock::new();
+    let wakes = Arc::new(CountWakes::default());
+    let waker = Waker::from(wakes.clone());
+    let mut cx = Context::from_waker(&waker);
+
+    let mut short = pin!(clock.sleep(Duration::from_secs(1)));
+    let mut long = pin!(clock.sleep(Duration::from_secs(3)));
+    assert_eq!(short.as_mut().poll(&mut cx), Poll::Pending);
+    assert_eq!(long.as_mut().poll(&mut cx), Poll::Pending);
+
+    // Only the sleeps that reached their deadline are woken
+    clock.advance(Duration::from_secs(2));
+    assert_eq!(wakes.0.load(Ordering::Relaxed), 1);
+    assert_eq!(short.as_mut().poll(&mut cx), Poll::Ready(()));
+    assert_eq!(long.as_mut().poll(&mut cx), Poll::Pending);
+
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(wakes.0.load(Ordering::Relaxed), 2);
+    assert_eq!(long.as_mut().poll(&mut cx), Poll::Ready(()));
+
+    // A sleep that was never polled doesn't need a wake
+    let mut zero = pin!(clock.sleep(Duration::ZERO));
+    assert_eq!(zero.as_mut().poll(&mut cx), Poll::Ready(()));
+}
+
+#[test]
+fn dropped_sleep_is_not_woken() {
+    let clock = ManualClock::new();
+    let wakes = Arc::new(CountWakes::default());
+    let waker = Waker::from(wakes.clone());
+    let mut cx = Context::from_waker(&waker);
+
+    let mut sleep = Box::pin(clock.sleep(Duration::from_secs(1)));
+    assert_eq!(sleep.as_mut().poll(&mut cx), Poll::Pending);
+    drop(sleep);
+
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(wakes.0.load(Ordering::Relaxed), 0);
+}
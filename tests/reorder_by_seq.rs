@@ -0,0 +1,59 @@
+//! Sequences whose first items arrive out of order.
+
+use futures::executor::block_on;
+use futures::stream;
+use streamies::{SeqError, SeqEvent, Streamies as _, TryStreamies as _};
+
+#[test]
+fn first_item_out_of_order() {
+    let events = stream::iter(vec![2, 0, 1, 3])
+        .reorder_by_seq(|seq| *seq, 10, 10)
+        .collect_vec();
+
+    assert_eq!(
+        block_on(events),
+        vec![
+            SeqEvent::Item(0),
+            SeqEvent::Item(1),
+            SeqEvent::Item(2),
+            SeqEvent::Item(3)
+        ]
+    );
+}
+
+#[test]
+fn first_item_out_of_order_with_start() {
+    let events = stream::iter(vec![3, 1, 2, 1])
+        .reorder_by_seq(|seq| *seq, 10, 10)
+        .with_start(1)
+        .collect_vec();
+
+    assert_eq!(
+        block_on(events),
+        vec![
+            SeqEvent::Item(1),
+            SeqEvent::Item(2),
+            SeqEvent::Item(3),
+            SeqEvent::Duplicate { seq: 1, item: 1 }
+        ]
+    );
+}
+
+#[test]
+fn first_ok_out_of_order() {
+    let results = stream::iter(vec![Ok(12), Ok(10), Err("oops"), Ok(11), Ok(10)])
+        .reorder_by_seq_ok(|seq| *seq, 10, 10)
+        .with_start(10)
+        .collect_vec();
+
+    assert_eq!(
+        block_on(results),
+        vec![
+            Ok(10),
+            Err(SeqError::Upstream("oops")),
+            Ok(11),
+            Ok(12),
+            Err(SeqError::Duplicate { seq: 10 })
+        ]
+    );
+}
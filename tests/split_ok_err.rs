@@ -0,0 +1,163 @@
+//! Backpressure and dropping behaviour of the halves of `split_ok_err`.
+
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+
+use futures::stream::{self, FusedStream, Stream};
+use futures::task::noop_waker_ref;
+use streamies::SplitErr;
+use streamies::TryStreamies as _;
+
+fn poll<St: Stream + Unpin>(stream: &mut St) -> Poll<Option<St::Item>> {
+    Pin::new(stream).poll_next(&mut Context::from_waker(noop_waker_ref()))
+}
+
+#[test]
+fn both_buffers_fill() {
+    let stream = stream::iter(vec![
+        Ok(1),
+        Ok(2),
+        Ok(3),
+        Err("a"),
+        Err("b"),
+        Err("c"),
+        Ok(4),
+    ]);
+    let (mut oks, mut errs) = stream.split_ok_err(2);
+
+    // The values are buffered for the ok half, until its buffer is full
+    assert_eq!(poll(&mut errs), Poll::Pending);
+    assert_eq!(oks.size_hint().0, 2);
+
+    assert_eq!(poll(&mut oks), Poll::Ready(Some(1)));
+    assert_eq!(poll(&mut oks), Poll::Ready(Some(2)));
+    assert_eq!(poll(&mut oks), Poll::Ready(Some(3)));
+
+    // Same for the errors
+    assert_eq!(poll(&mut oks), Poll::Pending);
+    assert_eq!(errs.size_hint().0, 2);
+
+    assert_eq!(poll(&mut errs), Poll::Ready(Some("a")));
+    assert_eq!(poll(&mut errs), Poll::Ready(Some("b")));
+    assert_eq!(poll(&mut errs), Poll::Ready(Some("c")));
+    assert_eq!(poll(&mut errs), Poll::Ready(None));
+    assert!(errs.is_terminated());
+
+    // The end of the stream got reached by the error half, but the ok half still has its buffer
+    assert!(!oks.is_terminated());
+    assert_eq!(poll(&mut oks), Poll::Ready(Some(4)));
+    assert_eq!(poll(&mut oks), Poll::Ready(None));
+    assert!(oks.is_terminated());
+}
+
+#[test]
+fn drop_ok_half_then_drain_errors() {
+    let stream = stream::iter(vec![Ok(1), Ok(2), Err("a"), Ok(3), Err("b")]);
+    let (oks, mut errs) = stream.split_ok_err(2);
+
+    assert_eq!(poll(&mut errs), Poll::Pending);
+
+    // The buffered values are discarded, and the next ones aren't buffered anymore
+    drop(oks);
+    assert_eq!(poll(&mut errs), Poll::Ready(Some("a")));
+    assert_eq!(poll(&mut errs), Poll::Ready(Some("b")));
+    assert_eq!(poll(&mut errs), Poll::Ready(None));
+    assert!(errs.is_terminated());
+}
+
+#[test]
+fn drop_err_half_then_drain_values() {
+    let stream = stream::iter(vec![Err("a"), Err("b"), Ok(1), Err("c"), Ok(2)]);
+    let (mut oks, errs) = stream.split_ok_err(2);
+
+    assert_eq!(poll(&mut oks), Poll::Pending);
+
+    drop(errs);
+    assert_eq!(poll(&mut oks), Poll::Ready(Some(1)));
+    assert_eq!(poll(&mut oks), Poll::Ready(Some(2)));
+    assert_eq!(poll(&mut oks), Poll::Ready(None));
+    assert!(oks.is_terminated());
+}
+
+type Source = Pin<Box<dyn Stream<Item = Result<i32, &'static str>> + Send>>;
+
+/// Waker polling the error half as soon as it gets woken, like an executor running the task inline would
+struct PollOnWake {
+    stream: Mutex<Option<SplitErr<Source>>>,
+    polled: Mutex<Vec<Poll<Option<&'static str>>>>,
+}
+
+impl PollOnWake {
+    fn poll(self: &Arc<Self>) {
+        let waker = Waker::from(self.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        if let Some(stream) = self.stream.lock().unwrap().as_mut() {
+            let poll = Pin::new(stream).poll_next(&mut cx);
+            self.polled.lock().unwrap().push(poll);
+        }
+    }
+}
+
+impl Wake for PollOnWake {
+    fn wake(self: Arc<Self>) {
+        self.poll();
+    }
+}
+
+#[test]
+fn other_half_woken_outside_of_the_lock() {
+    let stream: Source = Box::pin(stream::iter(vec![Ok(1), Err("a")]));
+    let (mut oks, errs) = stream.split_ok_err(1);
+
+    let errs = Arc::new(PollOnWake {
+        stream: Mutex::new(Some(errs)),
+        polled: Mutex::new(Vec::new()),
+    });
+    errs.poll();
+
+    // Making room in the buffer wakes the error half, which polls the shared stream right away
+    assert_eq!(poll(&mut oks), Poll::Ready(Some(1)));
+    assert_eq!(
+        *errs.polled.lock().unwrap(),
+        vec![Poll::Pending, Poll::Ready(Some("a"))]
+    );
+
+    // Break the reference cycle between the waker and the stream
+    errs.stream.lock().unwrap().take();
+}
+
+#[test]
+fn other_half_polled_during_the_inner_poll() {
+    // The inner stream wakes the halves while being polled, so the error half polls them from within the inner poll
+    let mut polls = 0;
+    let stream: Source = Box::pin(stream::poll_fn(move |cx| {
+        polls += 1;
+        match polls {
+            1 => Poll::Pending,
+            2 => {
+                cx.waker().wake_by_ref();
+                Poll::Ready(Some(Ok(1)))
+            }
+            3 => Poll::Ready(Some(Err("a"))),
+            _ => Poll::Ready(None),
+        }
+    }));
+    let (mut oks, errs) = stream.split_ok_err(1);
+
+    let errs = Arc::new(PollOnWake {
+        stream: Mutex::new(Some(errs)),
+        polled: Mutex::new(Vec::new()),
+    });
+    errs.poll();
+
+    // The error half doesn't wait for the lock, and gets woken again once the inner stream is back
+    assert_eq!(poll(&mut oks), Poll::Ready(Some(1)));
+    assert_eq!(
+        *errs.polled.lock().unwrap(),
+        vec![Poll::Pending, Poll::Pending, Poll::Ready(Some("a"))]
+    );
+
+    errs.stream.lock().unwrap().take();
+}
@@ -0,0 +1,56 @@
+//! Concurrency of `then_ok_buffered` when errors are interleaved with the values.
+
+use std::cell::Cell;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::channel::oneshot;
+use futures::stream::{self, Stream};
+use futures::task::noop_waker_ref;
+use streamies::TryStreamies as _;
+
+fn poll<St: Stream + Unpin>(stream: &mut St) -> Poll<Option<St::Item>> {
+    Pin::new(stream).poll_next(&mut Context::from_waker(noop_waker_ref()))
+}
+
+#[test]
+fn errors_do_not_take_running_slots() {
+    let (first, first_receiver) = oneshot::channel::<i32>();
+    let (second, second_receiver) = oneshot::channel();
+    let (third, third_receiver) = oneshot::channel();
+    let started = Cell::new(0);
+
+    let stream = stream::iter(vec![
+        Ok(first_receiver),
+        Err("a"),
+        Ok(second_receiver),
+        Err("b"),
+        Ok(third_receiver),
+    ]);
+    let mut stream = Box::pin(stream.then_ok_buffered(2, |receiver| {
+        started.set(started.get() + 1);
+        async move { receiver.await.map_err(|_| "cancelled") }
+    }));
+
+    // The error between the values doesn't prevent two futures from running
+    assert_eq!(poll(&mut stream), Poll::Pending);
+    assert_eq!(started.get(), 2);
+
+    // The second future finishing before the first one makes room for the third
+    second.send(2).unwrap();
+    assert_eq!(poll(&mut stream), Poll::Pending);
+    assert_eq!(poll(&mut stream), Poll::Pending);
+    assert_eq!(started.get(), 3);
+
+    // The outputs are still yielded in order
+    first.send(1).unwrap();
+    assert_eq!(poll(&mut stream), Poll::Ready(Some(Ok(1))));
+    assert_eq!(poll(&mut stream), Poll::Ready(Some(Err("a"))));
+    assert_eq!(poll(&mut stream), Poll::Ready(Some(Ok(2))));
+    assert_eq!(poll(&mut stream), Poll::Ready(Some(Err("b"))));
+    assert_eq!(poll(&mut stream), Poll::Pending);
+
+    third.send(3).unwrap();
+    assert_eq!(poll(&mut stream), Poll::Ready(Some(Ok(3))));
+    assert_eq!(poll(&mut stream), Poll::Ready(None));
+}
@@ -0,0 +1,115 @@
+//! Time based adaptors, driven by tokio's paused clock.
+#![cfg(feature = "tokio")]
+
+use core::time::Duration;
+
+use futures::channel::mpsc;
+use futures::stream::{self, StreamExt as _};
+use streamies::{
+    CircuitBreakerConfig, CircuitBreakerError, CircuitState, TokioStreamies as _, TokioTimer,
+    TryStreamies as _,
+};
+use tokio::time::{sleep, Instant};
+
+#[tokio::test(start_paused = true)]
+async fn chunks_deadline_yields_partial_chunk() {
+    let (sender, receiver) = mpsc::unbounded();
+    let mut stream = Box::pin(receiver.chunks_deadline(5, Duration::from_secs(3)));
+    let start = Instant::now();
+
+    sender.unbounded_send(1).unwrap();
+    sender.unbounded_send(2).unwrap();
+
+    // The chunk isn't full, so it is only yielded once the deadline is reached
+    assert_eq!(stream.next().await, Some(vec![1, 2]));
+    assert_eq!(start.elapsed(), Duration::from_secs(3));
+
+    // The deadline restarts with the first item of the next chunk
+    sleep(Duration::from_secs(10)).await;
+    sender.unbounded_send(3).unwrap();
+    assert_eq!(stream.next().await, Some(vec![3]));
+    assert_eq!(start.elapsed(), Duration::from_secs(16));
+
+    // The last partial chunk is yielded as soon as the stream ends
+    sender.unbounded_send(4).unwrap();
+    drop(sender);
+    assert_eq!(stream.next().await, Some(vec![4]));
+    assert_eq!(stream.next().await, None);
+    assert_eq!(start.elapsed(), Duration::from_secs(16));
+}
+
+#[tokio::test(start_paused = true)]
+async fn item_timeout_repeats_on_stalled_stream() {
+    let (sender, receiver) = mpsc::unbounded();
+    let mut stream = Box::pin(receiver.item_timeout(Duration::from_secs(2)));
+    let start = Instant::now();
+
+    for timeouts in 1..=3 {
+        assert!(stream.next().await.unwrap().is_err());
+        assert_eq!(start.elapsed(), Duration::from_secs(2 * timeouts));
+    }
+
+    // The stream isn't cancelled by the timeouts
+    sleep(Duration::from_secs(1)).await;
+    sender.unbounded_send(1).unwrap();
+    assert_eq!(stream.next().await, Some(Ok(1)));
+    assert_eq!(start.elapsed(), Duration::from_secs(7));
+
+    // The timer restarts after the item
+    assert!(stream.next().await.unwrap().is_err());
+    assert_eq!(start.elapsed(), Duration::from_secs(9));
+
+    drop(sender);
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn pace_slower_source() {
+    let stream = stream::iter(1..=3).then(|i| async move {
+        sleep(Duration::from_secs(3)).await;
+        i
+    });
+    let mut stream = Box::pin(stream.pace(Duration::from_secs(1)));
+    let start = Instant::now();
+
+    // The source is slower than the period, so the items are yielded as soon as they are ready
+    for i in 1..=3 {
+        assert_eq!(stream.next().await, Some(i));
+        assert_eq!(start.elapsed(), Duration::from_secs(3 * i));
+    }
+
+    // A period already passed since the last tick, so the end isn't delayed either
+    assert_eq!(stream.next().await, None);
+    assert_eq!(start.elapsed(), Duration::from_secs(9));
+    assert_eq!(stream.next().await, None);
+    assert_eq!(start.elapsed(), Duration::from_secs(9));
+}
+
+#[tokio::test(start_paused = true)]
+async fn circuit_breaker_cooldown_on_paused_clock() {
+    let (sender, receiver) = mpsc::unbounded::<Result<i32, &str>>();
+    let config = CircuitBreakerConfig::new(TokioTimer::new())
+        .window(2)
+        .cooldown(Duration::from_secs(10));
+    let mut stream = Box::pin(receiver.circuit_breaker(config));
+    let handle = stream.handle();
+
+    sender.unbounded_send(Err("down")).unwrap();
+    sender.unbounded_send(Err("down")).unwrap();
+    stream.next().await;
+    stream.next().await;
+    assert_eq!(handle.state(), CircuitState::Open);
+
+    sleep(Duration::from_secs(9)).await;
+    sender.unbounded_send(Ok(1)).unwrap();
+    assert!(matches!(
+        stream.next().await,
+        Some(Err(CircuitBreakerError::Open(_)))
+    ));
+
+    // The cooldown is measured on tokio's paused clock
+    sleep(Duration::from_secs(1)).await;
+    sender.unbounded_send(Ok(2)).unwrap();
+    assert_eq!(stream.next().await, Some(Ok(2)));
+    assert_eq!(handle.state(), CircuitState::Closed);
+}